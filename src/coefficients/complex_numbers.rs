@@ -0,0 +1,42 @@
+#![forbid(unsafe_code)]
+//! # Complex Numbers
+//!
+//! Complex numbers are provided by the `num` crate.
+//! `Complex<T>` implements `Zero`, `One`, the arithmetic operations and the multiplication by a `T`,
+//! so a `Polynomial<T>` can be evaluated at complex points, and `Polynomial<Complex<T>>` is supported directly.
+//!
+//! ```
+//! use rust_polynomials_lib::coefficients::Complex;
+//! use rust_polynomials_lib::polynomials::Polynomial;
+//! let p = Polynomial::from_vector(vec![1.0, 0.0, 1.0]); // 1 + x^2
+//! assert_eq!(p.eval(Complex::new(0.0, 1.0)), Complex::new(0.0, 0.0));
+//! ```
+
+pub use num::Complex;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomials::Polynomial;
+    use num::{One, Zero};
+
+    #[test]
+    fn test_eval_real_polynomial_at_complex_point() {
+        let p = Polynomial::from_vector(vec![2, -3, 1]); // (x - 1)(x - 2)
+        assert_eq!(p.eval(Complex::new(1, 1)), Complex::new(-1, -1));
+        assert_eq!(p.eval(Complex::new(2, 0)), Complex::zero());
+    }
+
+    #[test]
+    fn test_complex_polynomial() {
+        let i = Complex::new(0.0, 1.0);
+        // (x - i)(x + i) = x^2 + 1
+        let p = (Polynomial::x() - i) * (Polynomial::x() + i);
+        assert_eq!(
+            p,
+            Polynomial::from_vector(vec![Complex::one(), Complex::zero(), Complex::one()])
+        );
+        assert_eq!(p.eval(i), Complex::zero());
+        assert_eq!(p.eval(Complex::new(2.0, 0.0)), Complex::new(5.0, 0.0));
+    }
+}
@@ -0,0 +1,570 @@
+#![forbid(unsafe_code)]
+//! # Dynamic Matrices
+
+use num::{One, Zero};
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+use super::SquareMatrix;
+
+/// A heap-allocated matrix with dimensions known at runtime, possibly rectangular.
+///
+/// Unlike [`SquareMatrix`], the elements only need to be `Clone`,
+/// so a `Matrix` can hold polynomials or other matrices.
+///
+/// Since `Zero::zero()` and `One::one()` cannot know the dimensions,
+/// they return a *scalar* matrix `c I` of unspecified size,
+/// which takes the size of the other operand in the ring operations.
+/// The zero matrix takes any shape, so sums starting from `Zero::zero()` work for rectangular matrices too.
+/// This is what makes `Polynomial::eval` work with a `Matrix` argument.
+#[derive(Clone, Debug)]
+pub struct Matrix<T> {
+    repr: Repr<T>,
+}
+
+#[derive(Clone, Debug)]
+enum Repr<T> {
+    /// `c I` of unspecified size.
+    Scalar(T),
+    /// Elements stored row by row.
+    Dense {
+        rows: usize,
+        cols: usize,
+        data: Vec<T>,
+    },
+}
+
+impl<T> Matrix<T>
+where
+    T: Clone,
+{
+    /// Creates a new `Matrix` with the given dimensions from its elements listed row by row.
+    /// # Panics
+    /// Panics if the number of elements is not `rows * cols`.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::Matrix;
+    /// let m = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(m[(1, 0)], 4);
+    /// ```
+    pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Self {
+        assert_eq!(data.len(), rows * cols, "matrix dimensions must agree");
+        Self {
+            repr: Repr::Dense { rows, cols, data },
+        }
+    }
+
+    /// Creates a new `Matrix` from a vector of rows.
+    /// # Panics
+    /// Panics if the rows have different lengths.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let cols = rows.first().map_or(0, |row| row.len());
+        let count = rows.len();
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "matrix dimensions must agree"
+        );
+        Self::new(count, cols, rows.into_iter().flatten().collect())
+    }
+
+    /// Creates a new `Matrix` whose element at `(row, col)` is `f(row, col)`.
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let mut data = Vec::with_capacity(rows * cols);
+        for row in 0..rows {
+            for col in 0..cols {
+                data.push(f(row, col));
+            }
+        }
+        Self::new(rows, cols, data)
+    }
+
+    /// Creates a scalar matrix `c I` of unspecified size, as returned by `Zero` and `One`.
+    pub fn scalar(c: T) -> Self {
+        Self {
+            repr: Repr::Scalar(c),
+        }
+    }
+
+    /// Returns the dimensions `(rows, cols)` of the matrix, or `None` for a scalar matrix of unspecified size.
+    pub fn shape(&self) -> Option<(usize, usize)> {
+        match &self.repr {
+            Repr::Scalar(_) => None,
+            Repr::Dense { rows, cols, .. } => Some((*rows, *cols)),
+        }
+    }
+
+    /// Returns the number of rows.
+    /// # Panics
+    /// Panics for a scalar matrix of unspecified size.
+    pub fn rows(&self) -> usize {
+        self.shape().expect("scalar matrix has no dimensions").0
+    }
+
+    /// Returns the number of columns.
+    /// # Panics
+    /// Panics for a scalar matrix of unspecified size.
+    pub fn cols(&self) -> usize {
+        self.shape().expect("scalar matrix has no dimensions").1
+    }
+
+    /// Checks whether the matrix is a square matrix, scalar matrices are square.
+    pub fn is_square(&self) -> bool {
+        self.shape().is_none_or(|(rows, cols)| rows == cols)
+    }
+
+    /// Returns a reference to the element at `(row, col)`, or `None` if it is out of bounds
+    /// or the matrix is a scalar matrix of unspecified size.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        match &self.repr {
+            Repr::Dense { rows, cols, data } if row < *rows && col < *cols => {
+                Some(&data[row * cols + col])
+            }
+            _ => None,
+        }
+    }
+
+    /// Swaps two rows of the matrix.
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        if let Repr::Dense { cols, data, .. } = &mut self.repr {
+            for col in 0..*cols {
+                data.swap(a * *cols + col, b * *cols + col);
+            }
+        }
+    }
+
+    /// Swaps two columns of the matrix.
+    pub fn swap_cols(&mut self, a: usize, b: usize) {
+        if let Repr::Dense { rows, cols, data } = &mut self.repr {
+            for row in 0..*rows {
+                data.swap(row * *cols + a, row * *cols + b);
+            }
+        }
+    }
+
+    /// Returns the transposed matrix.
+    pub fn transpose(&self) -> Self {
+        match &self.repr {
+            Repr::Scalar(_) => self.clone(),
+            Repr::Dense { rows, cols, data } => {
+                Self::from_fn(*cols, *rows, |row, col| data[col * cols + row].clone())
+            }
+        }
+    }
+
+    /// Applies `f` to every element of the matrix.
+    pub fn map<U: Clone>(&self, mut f: impl FnMut(&T) -> U) -> Matrix<U> {
+        match &self.repr {
+            Repr::Scalar(c) => Matrix::scalar(f(c)),
+            Repr::Dense { rows, cols, data } => {
+                Matrix::new(*rows, *cols, data.iter().map(f).collect())
+            }
+        }
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Clone + Zero,
+{
+    /// Creates a new `Matrix` of the given dimensions filled with zeros.
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self::new(rows, cols, vec![T::zero(); rows * cols])
+    }
+
+    /// Returns the matrix as a vector of rows.
+    /// A scalar matrix of unspecified size is expanded to the given `size`.
+    pub fn to_rows(&self, size: usize) -> Vec<Vec<T>> {
+        let dense = self.to_dense(size, size);
+        let (rows, cols) = dense.shape().unwrap_or((0, 0));
+        (0..rows)
+            .map(|row| (0..cols).map(|col| dense[(row, col)].clone()).collect())
+            .collect()
+    }
+
+    /// Expands a scalar matrix to a dense `rows x cols` matrix, dense matrices are returned unchanged.
+    fn to_dense(&self, rows: usize, cols: usize) -> Self {
+        match &self.repr {
+            Repr::Scalar(c) => {
+                Self::from_fn(
+                    rows,
+                    cols,
+                    |row, col| {
+                        if row == col {
+                            c.clone()
+                        } else {
+                            T::zero()
+                        }
+                    },
+                )
+            }
+            Repr::Dense { .. } => self.clone(),
+        }
+    }
+
+    /// Checks whether the matrix is the scalar zero matrix of unspecified size.
+    fn is_scalar_zero(&self) -> bool {
+        matches!(&self.repr, Repr::Scalar(c) if c.is_zero())
+    }
+
+    /// Checks whether a scalar matrix can take the shape `rows x cols`:
+    /// the zero matrix takes any shape, the other scalar matrices `c I` only square ones.
+    fn fits_shape(&self, rows: usize, cols: usize) -> bool {
+        rows == cols || self.is_scalar_zero()
+    }
+
+    /// Expands a scalar operand to the size of the other operand, `None` if both operands are scalar.
+    /// # Panics
+    /// Panics if the dimensions of the operands differ.
+    fn dense_pair(self, other: Self) -> Option<(Self, Self)> {
+        match (self.shape(), other.shape()) {
+            (None, None) => None,
+            (Some((rows, cols)), None) => {
+                assert!(other.fits_shape(rows, cols), "matrix dimensions must agree");
+                let other = other.to_dense(rows, cols);
+                Some((self, other))
+            }
+            (None, Some((rows, cols))) => {
+                assert!(self.fits_shape(rows, cols), "matrix dimensions must agree");
+                Some((self.to_dense(rows, cols), other))
+            }
+            (Some(a), Some(b)) => {
+                assert_eq!(a, b, "matrix dimensions must agree");
+                Some((self, other))
+            }
+        }
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Clone + Zero + One,
+{
+    /// Creates the identity matrix of size `n`.
+    pub fn identity(n: usize) -> Self {
+        Self::scalar(T::one()).to_dense(n, n)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T>
+where
+    T: Clone,
+{
+    type Output = T;
+
+    /// # Panics
+    /// Panics if the index is out of bounds or the matrix is a scalar matrix of unspecified size.
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col).expect("matrix index out of bounds")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T>
+where
+    T: Clone,
+{
+    /// # Panics
+    /// Panics if the index is out of bounds or the matrix is a scalar matrix of unspecified size.
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        match &mut self.repr {
+            Repr::Dense { rows, cols, data } if row < *rows && col < *cols => {
+                &mut data[row * *cols + col]
+            }
+            _ => panic!("matrix index out of bounds"),
+        }
+    }
+}
+
+impl<T> PartialEq for Matrix<T>
+where
+    T: Clone + Zero + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.repr, &other.repr) {
+            (Repr::Scalar(a), Repr::Scalar(b)) => a == b,
+            (Repr::Dense { data: a, .. }, Repr::Dense { data: b, .. }) => {
+                self.shape() == other.shape() && a == b
+            }
+            (Repr::Scalar(_), Repr::Dense { rows, cols, .. }) => {
+                self.fits_shape(*rows, *cols) && self.to_dense(*rows, *cols) == *other
+            }
+            (Repr::Dense { rows, cols, .. }, Repr::Scalar(_)) => {
+                other.fits_shape(*rows, *cols) && *self == other.to_dense(*rows, *cols)
+            }
+        }
+    }
+}
+
+impl<T> Add for Matrix<T>
+where
+    T: Clone + Zero + Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if let (Repr::Scalar(a), Repr::Scalar(b)) = (&self.repr, &rhs.repr) {
+            return Self::scalar(a.clone() + b.clone());
+        }
+        let (lhs, rhs) = self.dense_pair(rhs).expect("dense operands");
+        let (rows, cols) = lhs.shape().unwrap_or((0, 0));
+        Self::from_fn(rows, cols, |row, col| {
+            lhs[(row, col)].clone() + rhs[(row, col)].clone()
+        })
+    }
+}
+
+impl<T> Sub for Matrix<T>
+where
+    T: Clone + Zero + Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        if let (Repr::Scalar(a), Repr::Scalar(b)) = (&self.repr, &rhs.repr) {
+            return Self::scalar(a.clone() - b.clone());
+        }
+        let (lhs, rhs) = self.dense_pair(rhs).expect("dense operands");
+        let (rows, cols) = lhs.shape().unwrap_or((0, 0));
+        Self::from_fn(rows, cols, |row, col| {
+            lhs[(row, col)].clone() - rhs[(row, col)].clone()
+        })
+    }
+}
+
+impl<T> Neg for Matrix<T>
+where
+    T: Clone + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(|it| -it.clone())
+    }
+}
+
+impl<T> Mul for Matrix<T>
+where
+    T: Clone + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        match (&self.repr, &rhs.repr) {
+            (Repr::Scalar(a), Repr::Scalar(b)) => Self::scalar(a.clone() * b.clone()),
+            (Repr::Scalar(a), Repr::Dense { .. }) => rhs.map(|it| a.clone() * it.clone()),
+            (Repr::Dense { .. }, Repr::Scalar(b)) => self.map(|it| it.clone() * b.clone()),
+            (
+                Repr::Dense { rows, cols, .. },
+                Repr::Dense {
+                    rows: rhs_rows,
+                    cols: rhs_cols,
+                    ..
+                },
+            ) => {
+                assert_eq!(cols, rhs_rows, "matrix dimensions must agree");
+                Self::from_fn(*rows, *rhs_cols, |row, col| {
+                    let mut sum = T::zero();
+                    for it in 0..*cols {
+                        sum = sum + self[(row, it)].clone() * rhs[(it, col)].clone();
+                    }
+                    sum
+                })
+            }
+        }
+    }
+}
+
+impl<T> Mul<T> for Matrix<T>
+where
+    T: Clone + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|it| it.clone() * rhs.clone())
+    }
+}
+
+impl<T> Zero for Matrix<T>
+where
+    T: Clone + Zero + Add<Output = T>,
+{
+    fn zero() -> Self {
+        Self::scalar(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        match &self.repr {
+            Repr::Scalar(c) => c.is_zero(),
+            Repr::Dense { data, .. } => data.iter().all(|it| it.is_zero()),
+        }
+    }
+}
+
+impl<T> One for Matrix<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Mul<Output = T>,
+{
+    fn one() -> Self {
+        Self::scalar(T::one())
+    }
+}
+
+impl<T, const N: usize> From<SquareMatrix<T, N>> for Matrix<T>
+where
+    T: Copy,
+{
+    fn from(matrix: SquareMatrix<T, N>) -> Self {
+        Self::from_fn(N, N, |row, col| matrix.data()[row][col])
+    }
+}
+
+impl<T, const N: usize> TryFrom<Matrix<T>> for SquareMatrix<T, N>
+where
+    T: Copy + Zero,
+{
+    type Error = Matrix<T>;
+
+    /// Converts a `N x N` matrix, a scalar matrix is expanded to the size `N`.
+    /// The matrix is given back if its dimensions do not match.
+    fn try_from(matrix: Matrix<T>) -> Result<Self, Self::Error> {
+        match matrix.shape() {
+            Some(shape) if shape != (N, N) => Err(matrix),
+            _ => {
+                let dense = matrix.to_dense(N, N);
+                Ok(SquareMatrix::new(std::array::from_fn(|row| {
+                    std::array::from_fn(|col| dense[(row, col)])
+                })))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomials::Polynomial;
+
+    #[test]
+    fn test_new_matrices() {
+        let a = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(a.shape(), Some((2, 3)));
+        assert_eq!(a, Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]));
+        assert_eq!(a, Matrix::from_fn(2, 3, |row, col| 3 * row + col + 1));
+        assert_eq!(a.get(1, 2), Some(&6));
+        assert_eq!(a.get(2, 0), None);
+        assert_eq!(a.to_rows(0), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "matrix dimensions must agree")]
+    fn test_new_matrices_wrong_length() {
+        Matrix::new(2, 2, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_add_sub_matrices() {
+        let a = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        let b = Matrix::new(2, 3, vec![6, 5, 4, 3, 2, 1]);
+        assert_eq!(a.clone() + b.clone(), Matrix::new(2, 3, vec![7; 6]));
+        assert_eq!(a - b, Matrix::new(2, 3, vec![-5, -3, -1, 1, 3, 5]));
+    }
+
+    #[test]
+    #[should_panic(expected = "matrix dimensions must agree")]
+    fn test_add_matrices_wrong_shape() {
+        let _ = Matrix::new(1, 2, vec![1, 2]) + Matrix::new(2, 1, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_mul_rectangular_matrices() {
+        let a = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        let b = Matrix::new(3, 1, vec![1, 0, -1]);
+        assert_eq!(a.clone() * b, Matrix::new(2, 1, vec![-2, -2]));
+        assert_eq!(a.clone() * 2, Matrix::new(2, 3, vec![2, 4, 6, 8, 10, 12]));
+        assert_eq!(-a.clone(), a.clone() * -1);
+        assert_eq!(a.transpose(), Matrix::new(3, 2, vec![1, 4, 2, 5, 3, 6]));
+    }
+
+    #[test]
+    fn test_scalar_matrices() {
+        let a = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+        assert_eq!(Matrix::<i32>::one() * a.clone(), a);
+        assert_eq!(
+            a.clone() + Matrix::one(),
+            Matrix::new(2, 2, vec![2, 2, 3, 5])
+        );
+        assert_eq!(Matrix::<i32>::identity(2), Matrix::one());
+        assert!((Matrix::<i32>::zero() * a.clone()).is_zero());
+        assert_eq!(Matrix::<i32>::zero() * a, Matrix::zeros(2, 2));
+        assert_ne!(Matrix::new(2, 3, vec![1, 0, 0, 0, 1, 0]), Matrix::one());
+        assert_eq!(Matrix::scalar(3).to_rows(2), vec![vec![3, 0], vec![0, 3]]);
+    }
+
+    #[test]
+    fn test_scalar_zero_takes_any_shape() {
+        let a = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(Matrix::zero() + a.clone(), a);
+        assert_eq!(a.clone() - Matrix::zero(), a);
+        let sum = [a.clone(), a.clone()]
+            .into_iter()
+            .fold(Matrix::zero(), |sum, it| sum + it);
+        assert_eq!(sum, a.clone() * 2);
+        assert_eq!(Matrix::<i64>::zeros(2, 3), Matrix::zero());
+        assert_eq!(Matrix::zero(), Matrix::<i64>::zeros(2, 3));
+        assert_ne!(a, Matrix::zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "matrix dimensions must agree")]
+    fn test_add_scalar_to_rectangular_matrix() {
+        let _ = Matrix::new(1, 2, vec![1, 2]) + Matrix::one();
+    }
+
+    #[test]
+    fn test_swap_rows_and_cols() {
+        let mut a = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        a.swap_rows(0, 1);
+        assert_eq!(a, Matrix::new(2, 3, vec![4, 5, 6, 1, 2, 3]));
+        a.swap_cols(0, 2);
+        assert_eq!(a, Matrix::new(2, 3, vec![6, 5, 4, 3, 2, 1]));
+        a[(0, 1)] = 0;
+        assert_eq!(a[(0, 1)], 0);
+    }
+
+    #[test]
+    fn test_square_matrix_conversions() {
+        let square = SquareMatrix::new([[1, 2], [3, 4]]);
+        let matrix = Matrix::from(square.clone());
+        assert_eq!(matrix, Matrix::new(2, 2, vec![1, 2, 3, 4]));
+        assert_eq!(SquareMatrix::try_from(matrix), Ok(square));
+        assert_eq!(
+            SquareMatrix::<i32, 2>::try_from(Matrix::one()),
+            Ok(SquareMatrix::one())
+        );
+        let rectangular = Matrix::new(1, 2, vec![1, 2]);
+        assert_eq!(
+            SquareMatrix::<i32, 2>::try_from(rectangular.clone()),
+            Err(rectangular)
+        );
+    }
+
+    #[test]
+    fn test_polynomial_eval_at_matrix() {
+        let p = Polynomial::from_vector(vec![1, 2, 3]); // 1 + 2x + 3x^2
+        let square = SquareMatrix::new([[1, 2], [3, 4]]);
+        let value = p.eval(Matrix::from(square.clone()));
+        assert_eq!(value, Matrix::from(p.eval(square)));
+    }
+
+    #[test]
+    fn test_matrix_of_polynomials() {
+        let x = Polynomial::<i32>::x();
+        let one = Polynomial::from_constant(1);
+        let a = Matrix::from_rows(vec![
+            vec![x.clone(), one.clone()],
+            vec![one.clone(), x.clone()],
+        ]);
+        let square = a.clone() * a;
+        assert_eq!(square[(0, 0)], x.clone() * x.clone() + one.clone());
+        assert_eq!(square[(0, 1)], x.clone() + x);
+    }
+}
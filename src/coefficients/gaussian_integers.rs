@@ -0,0 +1,322 @@
+#![forbid(unsafe_code)]
+//! # Gaussian Integers
+
+use num::integer::Integer;
+use num::{One, Zero};
+
+/// A Gaussian integer `a + bi` with integer `a` and `b`.
+/// The Gaussian integers form a Euclidean domain with the norm `a^2 + b^2`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct GaussianInteger {
+    re: i64,
+    im: i64,
+}
+
+/// Divides `numerator` by a positive `denominator`, rounding to the nearest integer.
+/// The computation is carried out in `i128`, so that `2 * numerator` cannot overflow.
+/// # Panics
+/// Panics if the rounded quotient does not fit in an `i64`.
+fn div_round(numerator: i128, denominator: i128) -> i64 {
+    let quotient = Integer::div_floor(&(2 * numerator + denominator), &(2 * denominator));
+    i64::try_from(quotient).expect("the quotient of Gaussian integers overflows i64")
+}
+
+impl GaussianInteger {
+    /// Create a new `GaussianInteger` from its real and imaginary parts.
+    pub fn new(re: i64, im: i64) -> Self {
+        Self { re, im }
+    }
+
+    /// Create the imaginary unit `i`.
+    pub fn i() -> Self {
+        Self { re: 0, im: 1 }
+    }
+
+    /// Get the real part of the `GaussianInteger`.
+    pub fn re(&self) -> i64 {
+        self.re
+    }
+
+    /// Get the imaginary part of the `GaussianInteger`.
+    pub fn im(&self) -> i64 {
+        self.im
+    }
+
+    /// Get the norm `re^2 + im^2` of the `GaussianInteger`.
+    /// It overflows `i64` once the norm exceeds `2^63 - 1`, which cannot happen when both parts are below `2^31`.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::GaussianInteger;
+    /// assert_eq!(GaussianInteger::new(3, -4).norm(), 25);
+    /// ```
+    pub fn norm(&self) -> i64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Get the complex conjugate of the `GaussianInteger`.
+    pub fn conj(&self) -> Self {
+        Self {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+
+    /// Checks whether the `GaussianInteger` is one of the units `1, -1, i, -i`.
+    pub fn is_unit(&self) -> bool {
+        self.norm() == 1
+    }
+
+    /// Euclidean division, returning the quotient and the remainder.
+    /// The quotient is `self / other` rounded to the nearest Gaussian integer,
+    /// so the norm of the remainder is at most half the norm of `other`.
+    /// The intermediate products are computed in `i128`, so any parts in the range of `i64` are supported,
+    /// as long as the quotient fits in `i64`.
+    /// # Panics
+    /// Panics if `other` is zero, or if the quotient overflows `i64`.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::GaussianInteger;
+    /// let a = GaussianInteger::new(7, 3);
+    /// let b = GaussianInteger::new(2, -1);
+    /// let (q, r) = a.div_rem(&b);
+    /// assert_eq!(q * b + r, a);
+    /// assert!(2 * r.norm() <= b.norm());
+    /// ```
+    pub fn div_rem(&self, other: &Self) -> (Self, Self) {
+        assert!(!other.is_zero(), "division by zero");
+        // self * conj(other) and the norm of other, in i128 so that they cannot overflow
+        let (a, b) = (self.re as i128, self.im as i128);
+        let (c, d) = (other.re as i128, other.im as i128);
+        let norm = c * c + d * d;
+        let quotient = Self {
+            re: div_round(a * c + b * d, norm),
+            im: div_round(b * c - a * d, norm),
+        };
+        (quotient, *self - quotient * *other)
+    }
+
+    /// Returns the associate of the `GaussianInteger` lying in the first quadrant,
+    /// i.e. with a positive real part and a non-negative imaginary part.
+    /// Zero is returned unchanged.
+    pub fn normalized(&self) -> Self {
+        let mut result = *self;
+        while !(result.is_zero() || (result.re > 0 && result.im >= 0)) {
+            result = result * Self::i();
+        }
+        result
+    }
+
+    /// Returns the greatest common divisor of two Gaussian integers, computed with the Euclidean algorithm.
+    /// The result is normalized to the first quadrant.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::GaussianInteger;
+    /// // 5 = (2 + i)(2 - i) and 3 + 4i = (2 + i)^2
+    /// let g = GaussianInteger::gcd(&GaussianInteger::new(5, 0), &GaussianInteger::new(3, 4));
+    /// assert_eq!(g, GaussianInteger::new(2, 1));
+    /// ```
+    pub fn gcd(a: &Self, b: &Self) -> Self {
+        let mut a = *a;
+        let mut b = *b;
+        while !b.is_zero() {
+            let r = a.div_rem(&b).1;
+            a = b;
+            b = r;
+        }
+        a.normalized()
+    }
+}
+
+impl std::ops::Add for GaussianInteger {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            re: self.re + other.re,
+            im: self.im + other.im,
+        }
+    }
+}
+
+impl std::ops::Neg for GaussianInteger {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            re: -self.re,
+            im: -self.im,
+        }
+    }
+}
+
+impl std::ops::Sub for GaussianInteger {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + (-other)
+    }
+}
+
+/// The parts of the product are at most `sqrt(norm(a) norm(b))` in absolute value,
+/// so it cannot overflow `i64` when all the parts of the factors are below `2^31` in absolute value.
+impl std::ops::Mul for GaussianInteger {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self {
+            re: self.re * other.re - self.im * other.im,
+            im: self.re * other.im + self.im * other.re,
+        }
+    }
+}
+
+/// Exact division, e.g. for the fraction-free determinant.
+/// The Euclidean division is [`GaussianInteger::div_rem`].
+/// # Panics
+/// Panics if `other` is zero or does not divide `self`.
+impl std::ops::Div for GaussianInteger {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        let (quotient, remainder) = self.div_rem(&other);
+        assert!(remainder.is_zero(), "inexact division of Gaussian integers");
+        quotient
+    }
+}
+
+impl Zero for GaussianInteger {
+    fn zero() -> Self {
+        Self { re: 0, im: 0 }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re == 0 && self.im == 0
+    }
+}
+
+impl One for GaussianInteger {
+    fn one() -> Self {
+        Self { re: 1, im: 0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_gaussian_integers() {
+        let a = GaussianInteger::new(1, 2);
+        let b = GaussianInteger::new(3, -5);
+        assert_eq!(a + b, GaussianInteger::new(4, -3));
+        assert_eq!(a - b, GaussianInteger::new(-2, 7));
+    }
+
+    #[test]
+    fn test_mul_gaussian_integers() {
+        let a = GaussianInteger::new(1, 2);
+        let b = GaussianInteger::new(3, -5);
+        assert_eq!(a * b, GaussianInteger::new(13, 1));
+        assert_eq!(
+            GaussianInteger::i() * GaussianInteger::i(),
+            -GaussianInteger::one()
+        );
+    }
+
+    #[test]
+    fn test_norm_is_multiplicative() {
+        let a = GaussianInteger::new(1, 2);
+        let b = GaussianInteger::new(3, -5);
+        assert_eq!((a * b).norm(), a.norm() * b.norm());
+        assert_eq!(a * a.conj(), GaussianInteger::new(a.norm(), 0));
+    }
+
+    #[test]
+    fn test_div_rem_gaussian_integers() {
+        for (a, b) in [
+            ((27, 23), (8, 1)),
+            ((-7, 2), (1, 1)),
+            ((4, -9), (-3, -2)),
+            ((5, 5), (0, 2)),
+        ] {
+            let a = GaussianInteger::new(a.0, a.1);
+            let b = GaussianInteger::new(b.0, b.1);
+            let (q, r) = a.div_rem(&b);
+            assert_eq!(q * b + r, a);
+            assert!(2 * r.norm() <= b.norm());
+        }
+    }
+
+    #[test]
+    fn test_div_rem_large_gaussian_integers() {
+        // the norms are above 2^62, where doubling them overflows i64
+        let b = GaussianInteger::new(1 << 31, (1 << 31) + 5);
+        let q = GaussianInteger::new(3, -1);
+        let r = GaussianInteger::new(7, 2);
+        let a = q * b + r;
+        assert_eq!(a.div_rem(&b), (q, r));
+        assert_eq!(GaussianInteger::gcd(&(q * b), &b), b.normalized());
+    }
+
+    #[test]
+    fn test_exact_division() {
+        let a = GaussianInteger::new(2, 1);
+        let b = GaussianInteger::new(-3, 7);
+        assert_eq!((a * b) / b, a);
+        assert_eq!((a * b) / a, b);
+    }
+
+    #[test]
+    #[should_panic(expected = "inexact division of Gaussian integers")]
+    fn test_inexact_division() {
+        let _ = GaussianInteger::new(7, 3) / GaussianInteger::new(2, -1);
+    }
+
+    #[test]
+    #[should_panic(expected = "inexact division of Gaussian integers")]
+    fn test_polynomial_division_is_not_rounded() {
+        // x^2 + 1 is not divisible by 2x over the Gaussian integers
+        let g = |re| GaussianInteger::new(re, 0);
+        let p = crate::polynomials::Polynomial::from_vector(vec![g(1), g(0), g(1)]);
+        let d = crate::polynomials::Polynomial::from_vector(vec![g(0), g(2)]);
+        let _ = p.div_rem(&d);
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero")]
+    fn test_div_by_zero_gaussian_integers() {
+        let _ = GaussianInteger::one() / GaussianInteger::zero();
+    }
+
+    #[test]
+    fn test_gcd_gaussian_integers() {
+        let p = GaussianInteger::new(2, 1);
+        let a = p * GaussianInteger::new(3, -1) * GaussianInteger::new(1, 1);
+        let b = p * GaussianInteger::new(1, 4);
+        assert_eq!(GaussianInteger::gcd(&a, &b), p);
+        assert_eq!(
+            GaussianInteger::gcd(&GaussianInteger::new(3, 0), &GaussianInteger::new(2, 0)),
+            GaussianInteger::one()
+        );
+        assert_eq!(
+            GaussianInteger::gcd(&GaussianInteger::zero(), &GaussianInteger::new(0, -2)),
+            GaussianInteger::new(2, 0)
+        );
+    }
+
+    #[test]
+    fn test_normalized() {
+        let a = GaussianInteger::new(-1, 2);
+        let n = a.normalized();
+        assert!(n.re() > 0 && n.im() >= 0);
+        assert_eq!(n.norm(), a.norm());
+        assert!(GaussianInteger::zero().normalized().is_zero());
+        assert!(GaussianInteger::new(0, -1).is_unit());
+    }
+
+    #[test]
+    fn test_zero_one_gaussian_integers() {
+        assert!(GaussianInteger::zero().is_zero());
+        assert_eq!(GaussianInteger::one(), GaussianInteger::new(1, 0));
+    }
+}
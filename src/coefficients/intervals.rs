@@ -0,0 +1,318 @@
+#![forbid(unsafe_code)]
+//! # Intervals
+
+use num::{One, Zero};
+
+use crate::float::{two_product, two_sum};
+
+/// A closed interval of `f64` numbers.
+/// All the operations round outward, so the result always encloses every possible exact result.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Interval {
+    lo: f64,
+    hi: f64,
+}
+
+/// Rounds the result of an operation with the exact rounding error `error` down.
+fn round_down(result: f64, error: f64) -> f64 {
+    if error < 0.0 || error.is_nan() {
+        result.next_down()
+    } else {
+        result
+    }
+}
+
+/// Rounds the result of an operation with the exact rounding error `error` up.
+fn round_up(result: f64, error: f64) -> f64 {
+    if error > 0.0 || error.is_nan() {
+        result.next_up()
+    } else {
+        result
+    }
+}
+
+/// Checks whether the product or quotient `result` of the non-zero `a` and `b` fell below the normal range.
+/// The rounding error is then not representable, so it cannot tell whether `result` is exact.
+fn underflows(result: f64, a: f64, b: f64) -> bool {
+    result.abs() < f64::MIN_POSITIVE && a != 0.0 && b != 0.0
+}
+
+impl Interval {
+    /// Create a new `Interval` from its bounds.
+    /// # Panics
+    /// Panics if `lo > hi` or if one of the bounds is NaN.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::Interval;
+    /// let i = Interval::new(1.0, 2.0);
+    /// assert!(i.contains(1.5));
+    /// ```
+    pub fn new(lo: f64, hi: f64) -> Self {
+        assert!(lo <= hi, "invalid interval bounds");
+        Self { lo, hi }
+    }
+
+    /// Create a new `Interval` containing the single number `x`.
+    pub fn point(x: f64) -> Self {
+        Self::new(x, x)
+    }
+
+    /// Get the lower bound of the `Interval`.
+    pub fn lo(&self) -> f64 {
+        self.lo
+    }
+
+    /// Get the upper bound of the `Interval`.
+    pub fn hi(&self) -> f64 {
+        self.hi
+    }
+
+    /// Get the width `hi - lo` of the `Interval`, rounded up.
+    pub fn width(&self) -> f64 {
+        let (width, error) = two_sum(self.hi, -self.lo);
+        round_up(width, error)
+    }
+
+    /// Get the midpoint of the `Interval`.
+    /// The midpoint is only approximate, but always lies inside the `Interval`.
+    pub fn midpoint(&self) -> f64 {
+        (self.lo / 2.0 + self.hi / 2.0).clamp(self.lo, self.hi)
+    }
+
+    /// Checks whether `x` lies inside the `Interval`.
+    pub fn contains(&self, x: f64) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// Checks whether `other` lies inside the `Interval`.
+    pub fn encloses(&self, other: &Self) -> bool {
+        self.lo <= other.lo && other.hi <= self.hi
+    }
+
+    /// Returns the intersection of two intervals, or `None` if they are disjoint.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let lo = self.lo.max(other.lo);
+        let hi = self.hi.min(other.hi);
+        if lo <= hi {
+            Some(Self { lo, hi })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the smallest interval containing both intervals.
+    pub fn hull(&self, other: &Self) -> Self {
+        Self {
+            lo: self.lo.min(other.lo),
+            hi: self.hi.max(other.hi),
+        }
+    }
+}
+
+impl std::ops::Add for Interval {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let (lo, lo_error) = two_sum(self.lo, other.lo);
+        let (hi, hi_error) = two_sum(self.hi, other.hi);
+        Self {
+            lo: round_down(lo, lo_error),
+            hi: round_up(hi, hi_error),
+        }
+    }
+}
+
+impl std::ops::Neg for Interval {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl std::ops::Sub for Interval {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + (-other)
+    }
+}
+
+impl std::ops::Mul for Interval {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let mut lo = f64::INFINITY;
+        let mut hi = f64::NEG_INFINITY;
+        for a in [self.lo, self.hi] {
+            for b in [other.lo, other.hi] {
+                let (product, error) = two_product(a, b);
+                if underflows(product, a, b) {
+                    lo = lo.min(product.next_down());
+                    hi = hi.max(product.next_up());
+                } else {
+                    lo = lo.min(round_down(product, error));
+                    hi = hi.max(round_up(product, error));
+                }
+            }
+        }
+        Self { lo, hi }
+    }
+}
+
+impl std::ops::Mul<f64> for Interval {
+    type Output = Self;
+
+    fn mul(self, other: f64) -> Self {
+        self * Self::point(other)
+    }
+}
+
+impl std::ops::Div for Interval {
+    type Output = Self;
+
+    /// # Panics
+    /// Panics if `other` contains zero.
+    fn div(self, other: Self) -> Self {
+        assert!(
+            !other.contains(0.0),
+            "division by an interval containing zero"
+        );
+        let mut lo = f64::INFINITY;
+        let mut hi = f64::NEG_INFINITY;
+        for a in [self.lo, self.hi] {
+            for b in [other.lo, other.hi] {
+                let quotient = a / b;
+                let residual = quotient.mul_add(b, -a);
+                // `quotient * b - a` has the sign of `(quotient - a / b) * b`
+                let error = if b > 0.0 { -residual } else { residual };
+                if underflows(quotient, a, b) {
+                    lo = lo.min(quotient.next_down());
+                    hi = hi.max(quotient.next_up());
+                } else {
+                    lo = lo.min(round_down(quotient, error));
+                    hi = hi.max(round_up(quotient, error));
+                }
+            }
+        }
+        Self { lo, hi }
+    }
+}
+
+impl Zero for Interval {
+    fn zero() -> Self {
+        Self { lo: 0.0, hi: 0.0 }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.lo == 0.0 && self.hi == 0.0
+    }
+}
+
+impl One for Interval {
+    fn one() -> Self {
+        Self { lo: 1.0, hi: 1.0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_intervals() {
+        let a = Interval::new(1.0, 2.0);
+        let b = Interval::new(-3.0, 0.5);
+        assert_eq!(a + b, Interval::new(-2.0, 2.5));
+    }
+
+    #[test]
+    fn test_add_intervals_rounds_outward() {
+        let c = Interval::point(0.1) + Interval::point(0.2);
+        assert!(c.lo() < c.hi());
+        assert!(c.contains(0.1 + 0.2));
+        assert_eq!(c.width(), (0.1f64 + 0.2).next_up() - (0.1f64 + 0.2));
+    }
+
+    #[test]
+    fn test_sub_intervals() {
+        let a = Interval::new(1.0, 2.0);
+        let b = Interval::new(-3.0, 0.5);
+        assert_eq!(a - b, Interval::new(0.5, 5.0));
+    }
+
+    #[test]
+    fn test_neg_intervals() {
+        assert_eq!(-Interval::new(-1.0, 2.0), Interval::new(-2.0, 1.0));
+    }
+
+    #[test]
+    fn test_mul_intervals() {
+        let a = Interval::new(-1.0, 2.0);
+        let b = Interval::new(-3.0, 0.5);
+        assert_eq!(a * b, Interval::new(-6.0, 3.0));
+        assert_eq!(a * 2.0, Interval::new(-2.0, 4.0));
+    }
+
+    #[test]
+    fn test_mul_intervals_rounds_outward() {
+        let third = Interval::point(1.0) / Interval::point(3.0);
+        assert!(third.lo() < third.hi());
+        let one = third * Interval::point(3.0);
+        assert!(one.contains(1.0));
+    }
+
+    #[test]
+    fn test_mul_and_div_intervals_underflow() {
+        let tiny = Interval::point(1e-200) * Interval::point(1e-200);
+        assert!(tiny.lo() <= 0.0 && tiny.hi() > 0.0);
+        let tiny = Interval::point(-1e-300) / Interval::point(1e30);
+        assert!(tiny.lo() < 0.0 && tiny.hi() >= 0.0);
+        // the products of zero stay exact
+        assert_eq!(Interval::zero() * Interval::point(1e-200), Interval::zero());
+
+        let x = Interval::new(1e-200, 2e-200);
+        let p = crate::polynomials::Polynomial::from_vector(vec![0.0, 0.0, 1.0]);
+        assert!(p.to_intervals().eval(x).hi() > 0.0);
+        assert!(p.range(x).hi() > 0.0);
+    }
+
+    #[test]
+    fn test_div_intervals() {
+        let a = Interval::new(1.0, 2.0);
+        let b = Interval::new(-4.0, -2.0);
+        assert_eq!(a / b, Interval::new(-1.0, -0.25));
+    }
+
+    #[test]
+    #[should_panic(expected = "division by an interval containing zero")]
+    fn test_div_by_zero_intervals() {
+        let _ = Interval::one() / Interval::new(-1.0, 1.0);
+    }
+
+    #[test]
+    fn test_zero_one_intervals() {
+        assert!(Interval::zero().is_zero());
+        assert_eq!(Interval::one(), Interval::point(1.0));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = Interval::new(0.0, 2.0);
+        let b = Interval::new(1.0, 3.0);
+        assert_eq!(a.intersection(&b), Some(Interval::new(1.0, 2.0)));
+        assert_eq!(a.hull(&b), Interval::new(0.0, 3.0));
+        assert_eq!(a.intersection(&Interval::point(5.0)), None);
+        assert!(a.hull(&b).encloses(&a));
+        assert_eq!(b.midpoint(), 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid interval bounds")]
+    fn test_invalid_interval() {
+        Interval::new(1.0, 0.0);
+    }
+}
@@ -1,8 +1,12 @@
 #![forbid(unsafe_code)]
+// the index loops of the original matrix and polynomial code already failed this lint
+#![allow(clippy::needless_range_loop)]
 //! # Matrices
 
 use num::{One, Zero};
-use std::ops::{Add, Mul, Neg, Sub};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::polynomials::Polynomial;
 
 /// A square matrix.
 #[derive(Clone, Debug, PartialEq)]
@@ -23,6 +27,535 @@ where
     pub fn data(&self) -> &[[T; N]; N] {
         &self.data
     }
+
+    /// Returns the transposed matrix.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::SquareMatrix;
+    /// let m = SquareMatrix::new([[1, 2], [3, 4]]);
+    /// assert_eq!(m.transpose(), SquareMatrix::new([[1, 3], [2, 4]]));
+    /// ```
+    pub fn transpose(&self) -> Self {
+        let mut data = self.data;
+        for row in 0..N {
+            for col in 0..N {
+                data[row][col] = self.data[col][row];
+            }
+        }
+        Self { data }
+    }
+}
+
+impl<T, const N: usize> SquareMatrix<T, N>
+where
+    T: Add<Output = T> + Copy + Zero,
+{
+    /// Returns the trace of the matrix, i.e. the sum of its diagonal elements.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::SquareMatrix;
+    /// let m = SquareMatrix::new([[1, 2], [3, 4]]);
+    /// assert_eq!(m.trace(), 5);
+    /// ```
+    pub fn trace(&self) -> T {
+        let mut sum = T::zero();
+        for diag_it in 0..N {
+            sum = sum + self.data[diag_it][diag_it];
+        }
+        sum
+    }
+}
+
+impl<T, const N: usize> SquareMatrix<T, N>
+where
+    T: Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Copy + Zero + One,
+{
+    /// Returns the determinant of the matrix.
+    /// It is computed with the fraction-free Bareiss elimination:
+    /// every division is exact, so the result stays exact over integers such as `i64`,
+    /// as well as over fields such as `RationalNumber`.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::SquareMatrix;
+    /// let m = SquareMatrix::new([[2, 0, 1], [1, 3, 2], [1, 1, 2]]);
+    /// assert_eq!(m.determinant(), 6);
+    /// ```
+    pub fn determinant(&self) -> T {
+        let mut data = self.data;
+        let mut negated = false;
+        let mut previous_pivot = T::one();
+
+        for pivot in 0..N {
+            if data[pivot][pivot].is_zero() {
+                match (pivot + 1..N).find(|&row| !data[row][pivot].is_zero()) {
+                    Some(row) => {
+                        data.swap(pivot, row);
+                        negated = !negated;
+                    }
+                    None => return T::zero(),
+                }
+            }
+
+            for row in pivot + 1..N {
+                for col in pivot + 1..N {
+                    data[row][col] = (data[row][col] * data[pivot][pivot]
+                        - data[row][pivot] * data[pivot][col])
+                        / previous_pivot;
+                }
+            }
+            previous_pivot = data[pivot][pivot];
+        }
+
+        if negated {
+            T::zero() - previous_pivot
+        } else {
+            previous_pivot
+        }
+    }
+}
+
+impl<T, const N: usize> SquareMatrix<T, N>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + Zero + One,
+{
+    /// Returns the characteristic polynomial `det(x I - A)` of the matrix.
+    /// It is computed with the division-free Berkowitz algorithm in `O(N^4)` operations,
+    /// so it is exact over integers such as `i64` as well as over fields.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::SquareMatrix;
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let m = SquareMatrix::new([[1, 2], [3, 4]]);
+    /// assert_eq!(m.characteristic_polynomial(), Polynomial::from_vector(vec![-2, -5, 1]));
+    /// ```
+    pub fn characteristic_polynomial(&self) -> Polynomial<T> {
+        let a = &self.data;
+        // coefficients of the characteristic polynomial of the leading r x r submatrix,
+        // starting from the leading one
+        let mut coefficients = vec![T::one()];
+
+        for r in 0..N {
+            // the first column of the Toeplitz matrix:
+            // 1, -a_rr, -R S, -R A_r S, ..., -R A_r^(r - 1) S
+            let mut toeplitz = vec![T::one(), T::zero() - a[r][r]];
+            let mut vector: Vec<T> = (0..r).map(|row| a[row][r]).collect();
+            for _ in 0..r {
+                let mut product = T::zero();
+                for it in 0..r {
+                    product = product + a[r][it] * vector[it];
+                }
+                toeplitz.push(T::zero() - product);
+
+                vector = (0..r)
+                    .map(|row| {
+                        let mut sum = T::zero();
+                        for it in 0..r {
+                            sum = sum + a[row][it] * vector[it];
+                        }
+                        sum
+                    })
+                    .collect();
+            }
+
+            coefficients = (0..r + 2)
+                .map(|row| {
+                    let mut sum = T::zero();
+                    for col in 0..=row.min(r) {
+                        sum = sum + toeplitz[row - col] * coefficients[col];
+                    }
+                    sum
+                })
+                .collect();
+        }
+
+        coefficients.reverse();
+        Polynomial::from_vector(coefficients)
+    }
+}
+
+impl<T, const N: usize> SquareMatrix<T, N>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy + Zero + One,
+{
+    /// Checks whether `p(A) = 0`, where `A` is the matrix.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::SquareMatrix;
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let m = SquareMatrix::new([[0, 1], [0, 0]]);
+    /// assert!(m.annihilates(&Polynomial::x_pow(2)));
+    /// assert!(!m.annihilates(&Polynomial::x()));
+    /// ```
+    pub fn annihilates(&self, p: &Polynomial<T>) -> bool {
+        p.eval(self.clone()).is_zero()
+    }
+}
+
+impl<T, const N: usize> SquareMatrix<T, N>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Copy + Zero + One,
+{
+    /// Returns the minimal polynomial of the matrix over a field,
+    /// i.e. the monic polynomial `p` of the smallest degree such that `p(A) = 0`.
+    /// It is computed as the least common multiple of the minimal polynomials of the Krylov
+    /// sequences `e_i, A e_i, A^2 e_i, ...` of the standard basis vectors.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::SquareMatrix;
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let m = SquareMatrix::new([[2.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]]);
+    /// // (x - 2)(x - 3), while the characteristic polynomial is (x - 2)^2 (x - 3)
+    /// assert_eq!(m.minimal_polynomial(), Polynomial::from_vector(vec![6.0, -5.0, 1.0]));
+    /// ```
+    pub fn minimal_polynomial(&self) -> Polynomial<T> {
+        let mut minimal = Polynomial::from_constant(T::one());
+        for start in 0..N {
+            let mut unit = [T::zero(); N];
+            unit[start] = T::one();
+            let local = self.krylov_minimal_polynomial(unit);
+            let gcd = minimal.gcd(&local);
+            minimal = (minimal * local).div_rem(&gcd).0.monic();
+        }
+        minimal
+    }
+
+    /// Returns the monic polynomial `p` of the smallest degree such that `p(A) v = 0`.
+    fn krylov_minimal_polynomial(&self, v: [T; N]) -> Polynomial<T> {
+        // reduced Krylov vectors in echelon form: (vector, pivot, polynomial)
+        // with `vector = polynomial(A) v`
+        let mut basis: Vec<([T; N], usize, Polynomial<T>)> = Vec::new();
+        let mut power = v;
+        for degree in 0..=N {
+            let mut vector = power;
+            let mut polynomial = Polynomial::x_pow(degree);
+            for (reduced, pivot, reduced_polynomial) in &basis {
+                let factor = vector[*pivot] / reduced[*pivot];
+                if factor.is_zero() {
+                    continue;
+                }
+                for it in 0..N {
+                    vector[it] = vector[it] - factor * reduced[it];
+                }
+                polynomial = polynomial - reduced_polynomial.clone() * factor;
+            }
+
+            match (0..N).find(|&it| !vector[it].is_zero()) {
+                Some(pivot) => basis.push((vector, pivot, polynomial)),
+                None => return polynomial,
+            }
+
+            power = std::array::from_fn(|row| {
+                let mut sum = T::zero();
+                for col in 0..N {
+                    sum = sum + self.data[row][col] * power[col];
+                }
+                sum
+            });
+        }
+        unreachable!("at most N Krylov vectors are linearly independent")
+    }
+}
+
+/// The solution set of a linear system `A x = b`, see [`SquareMatrix::solve`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum LinearSystemSolution<T, const N: usize> {
+    /// The system has exactly one solution.
+    Unique([T; N]),
+    /// The system has infinitely many solutions: `particular` plus any linear combination of `kernel`.
+    Infinite {
+        /// A solution of the system, with all the free variables set to zero.
+        particular: [T; N],
+        /// A basis of the null space of `A`.
+        kernel: Vec<[T; N]>,
+    },
+    /// The system has no solution.
+    Inconsistent,
+}
+
+/// Gauss–Jordan elimination over a field, applying the same row operations to `companion`.
+/// The first non-zero element of a column is chosen as the pivot.
+/// With `reduced` set, pivots are scaled to one and eliminated above as well as below.
+/// Returns the pivot columns, their number is the rank.
+fn eliminate<T, const N: usize, const M: usize>(
+    data: &mut [[T; N]; N],
+    companion: &mut [[T; M]; N],
+    reduced: bool,
+) -> Vec<usize>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Copy + Zero + One,
+{
+    let mut pivot_columns = Vec::new();
+    for col in 0..N {
+        let row = pivot_columns.len();
+        if row == N {
+            break;
+        }
+        let Some(pivot_row) = (row..N).find(|&it| !data[it][col].is_zero()) else {
+            continue;
+        };
+        data.swap(row, pivot_row);
+        companion.swap(row, pivot_row);
+
+        if reduced {
+            let pivot = data[row][col];
+            for it in col..N {
+                data[row][it] = data[row][it] / pivot;
+            }
+            for it in 0..M {
+                companion[row][it] = companion[row][it] / pivot;
+            }
+        }
+
+        let first_row = if reduced { 0 } else { row + 1 };
+        for other in first_row..N {
+            if other == row || data[other][col].is_zero() {
+                continue;
+            }
+            let factor = data[other][col] / data[row][col];
+            for it in col..N {
+                data[other][it] = data[other][it] - factor * data[row][it];
+            }
+            for it in 0..M {
+                companion[other][it] = companion[other][it] - factor * companion[row][it];
+            }
+            data[other][col] = T::zero();
+        }
+        pivot_columns.push(col);
+    }
+    pivot_columns
+}
+
+impl<T, const N: usize> SquareMatrix<T, N>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Copy + Zero + One,
+{
+    /// Returns a row echelon form of the matrix, obtained with Gaussian elimination.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::SquareMatrix;
+    /// let m = SquareMatrix::new([[0.0, 2.0], [1.0, 3.0]]);
+    /// assert_eq!(m.row_echelon_form(), SquareMatrix::new([[1.0, 3.0], [0.0, 2.0]]));
+    /// ```
+    pub fn row_echelon_form(&self) -> Self {
+        let mut data = self.data;
+        eliminate(&mut data, &mut [[]; N], false);
+        Self { data }
+    }
+
+    /// Returns the reduced row echelon form of the matrix, obtained with Gauss–Jordan elimination.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::SquareMatrix;
+    /// let m = SquareMatrix::new([[1.0, 2.0], [2.0, 4.0]]);
+    /// assert_eq!(m.reduced_row_echelon_form(), SquareMatrix::new([[1.0, 2.0], [0.0, 0.0]]));
+    /// ```
+    pub fn reduced_row_echelon_form(&self) -> Self {
+        let mut data = self.data;
+        eliminate(&mut data, &mut [[]; N], true);
+        Self { data }
+    }
+
+    /// Returns the rank of the matrix.
+    pub fn rank(&self) -> usize {
+        let mut data = self.data;
+        eliminate(&mut data, &mut [[]; N], false).len()
+    }
+
+    /// Returns the inverse of the matrix, or `None` if the matrix is singular.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::SquareMatrix;
+    /// let m = SquareMatrix::new([[2.0, 0.0], [0.0, 4.0]]);
+    /// assert_eq!(m.inverse(), Some(SquareMatrix::new([[0.5, 0.0], [0.0, 0.25]])));
+    /// assert_eq!(SquareMatrix::new([[1.0, 2.0], [2.0, 4.0]]).inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        let mut data = self.data;
+        let mut inverse = Self::one().data;
+        if eliminate(&mut data, &mut inverse, true).len() < N {
+            return None;
+        }
+        Some(Self { data: inverse })
+    }
+
+    /// Solves the linear system `A x = b`, where `A` is the matrix,
+    /// reporting whether the system has a unique solution, infinitely many solutions or none.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::{LinearSystemSolution, SquareMatrix};
+    /// let m = SquareMatrix::new([[1.0, 1.0], [1.0, -1.0]]);
+    /// assert_eq!(m.solve([3.0, 1.0]), LinearSystemSolution::Unique([2.0, 1.0]));
+    ///
+    /// let singular = SquareMatrix::new([[1.0, 1.0], [2.0, 2.0]]);
+    /// assert_eq!(singular.solve([1.0, 3.0]), LinearSystemSolution::Inconsistent);
+    /// ```
+    pub fn solve(&self, b: [T; N]) -> LinearSystemSolution<T, N> {
+        let mut data = self.data;
+        let mut rhs = b.map(|it| [it]);
+        let pivot_columns = eliminate(&mut data, &mut rhs, true);
+        let rank = pivot_columns.len();
+
+        if rhs[rank..].iter().any(|it| !it[0].is_zero()) {
+            return LinearSystemSolution::Inconsistent;
+        }
+
+        let mut particular = [T::zero(); N];
+        for (row, &col) in pivot_columns.iter().enumerate() {
+            particular[col] = rhs[row][0];
+        }
+        if rank == N {
+            return LinearSystemSolution::Unique(particular);
+        }
+
+        let kernel = (0..N)
+            .filter(|col| !pivot_columns.contains(col))
+            .map(|free| {
+                let mut vector = [T::zero(); N];
+                vector[free] = T::one();
+                for (row, &col) in pivot_columns.iter().enumerate() {
+                    vector[col] = T::zero() - data[row][free];
+                }
+                vector
+            })
+            .collect();
+        LinearSystemSolution::Infinite { particular, kernel }
+    }
+}
+
+/// The LU decomposition with partial pivoting `P A = L U` of a `SquareMatrix<f64, N>`,
+/// see [`SquareMatrix::lu`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct LuDecomposition<const N: usize> {
+    lower: SquareMatrix<f64, N>,
+    upper: SquareMatrix<f64, N>,
+    permutation: [usize; N],
+    swaps: usize,
+}
+
+impl<const N: usize> LuDecomposition<N> {
+    /// Returns the unit lower triangular factor `L`.
+    pub fn lower(&self) -> &SquareMatrix<f64, N> {
+        &self.lower
+    }
+
+    /// Returns the upper triangular factor `U`.
+    pub fn upper(&self) -> &SquareMatrix<f64, N> {
+        &self.upper
+    }
+
+    /// Returns the row permutation: row `i` of `P A` is row `permutation[i]` of `A`.
+    pub fn permutation(&self) -> &[usize; N] {
+        &self.permutation
+    }
+
+    /// Checks whether the decomposed matrix is singular, i.e. whether `U` has a zero on its diagonal.
+    pub fn is_singular(&self) -> bool {
+        (0..N).any(|it| self.upper.data[it][it] == 0.0)
+    }
+
+    /// Returns the determinant of the decomposed matrix.
+    pub fn determinant(&self) -> f64 {
+        let product: f64 = (0..N).map(|it| self.upper.data[it][it]).product();
+        if self.swaps % 2 == 1 {
+            -product
+        } else {
+            product
+        }
+    }
+
+    /// Solves `A x = b` by forward and backward substitution, or returns `None` if `A` is singular.
+    pub fn solve(&self, b: [f64; N]) -> Option<[f64; N]> {
+        if self.is_singular() {
+            return None;
+        }
+        let lower = &self.lower.data;
+        let upper = &self.upper.data;
+
+        let mut x = [0.0; N];
+        for row in 0..N {
+            let mut sum = b[self.permutation[row]];
+            for col in 0..row {
+                sum -= lower[row][col] * x[col];
+            }
+            x[row] = sum;
+        }
+        for row in (0..N).rev() {
+            let mut sum = x[row];
+            for col in row + 1..N {
+                sum -= upper[row][col] * x[col];
+            }
+            x[row] = sum / upper[row][row];
+        }
+        Some(x)
+    }
+
+    /// Returns the inverse of the decomposed matrix, or `None` if it is singular.
+    pub fn inverse(&self) -> Option<SquareMatrix<f64, N>> {
+        let mut data = [[0.0; N]; N];
+        for col in 0..N {
+            let mut unit = [0.0; N];
+            unit[col] = 1.0;
+            let x = self.solve(unit)?;
+            for row in 0..N {
+                data[row][col] = x[row];
+            }
+        }
+        Some(SquareMatrix { data })
+    }
+}
+
+impl<const N: usize> SquareMatrix<f64, N> {
+    /// Returns the LU decomposition of the matrix with partial pivoting:
+    /// at every step the element of largest magnitude in the column is chosen as the pivot.
+    /// The decomposition exists for singular matrices too, see [`LuDecomposition::is_singular`].
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::SquareMatrix;
+    /// let m = SquareMatrix::new([[1.0, 2.0], [3.0, 4.0]]);
+    /// let lu = m.lu();
+    /// assert_eq!(lu.permutation(), &[1, 0]);
+    /// assert_eq!(lu.determinant(), -2.0);
+    /// assert_eq!(lu.solve([5.0, 11.0]), Some([1.0, 2.0]));
+    /// ```
+    pub fn lu(&self) -> LuDecomposition<N> {
+        let mut upper = self.data;
+        let mut lower = [[0.0; N]; N];
+        let mut permutation: [usize; N] = std::array::from_fn(|it| it);
+        let mut swaps = 0;
+
+        for col in 0..N {
+            let pivot_row = (col..N)
+                .max_by(|&a, &b| upper[a][col].abs().total_cmp(&upper[b][col].abs()))
+                .unwrap_or(col);
+            if pivot_row != col {
+                upper.swap(col, pivot_row);
+                lower.swap(col, pivot_row);
+                permutation.swap(col, pivot_row);
+                swaps += 1;
+            }
+            lower[col][col] = 1.0;
+
+            let pivot = upper[col][col];
+            if pivot == 0.0 {
+                continue;
+            }
+            for row in col + 1..N {
+                let factor = upper[row][col] / pivot;
+                lower[row][col] = factor;
+                for it in col..N {
+                    upper[row][it] -= factor * upper[col][it];
+                }
+                upper[row][col] = 0.0;
+            }
+        }
+
+        LuDecomposition {
+            lower: Self { data: lower },
+            upper: Self { data: upper },
+            permutation,
+            swaps,
+        }
+    }
 }
 
 impl<T, const N: usize> Add for SquareMatrix<T, N>
@@ -157,6 +690,11 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::coefficients::RationalNumber;
+
+    fn r(n: i64) -> RationalNumber {
+        RationalNumber::new(n, 1)
+    }
 
     #[test]
     fn test_add_matrices() {
@@ -220,6 +758,252 @@ mod tests {
         assert_eq!(a.data(), &[[1, 2], [3, 4]]);
     }
 
+    #[test]
+    fn test_transpose_matrices() {
+        let a = SquareMatrix::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(
+            a.transpose(),
+            SquareMatrix::new([[1, 4, 7], [2, 5, 8], [3, 6, 9]])
+        );
+        assert_eq!(a.transpose().transpose(), a);
+    }
+
+    #[test]
+    fn test_trace_matrices() {
+        let a = SquareMatrix::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(a.trace(), 15);
+        assert_eq!(SquareMatrix::<i32, 0>::new([]).trace(), 0);
+    }
+
+    #[test]
+    fn test_determinant_matrices() {
+        let a = SquareMatrix::new([[3i64, 8], [4, 6]]);
+        assert_eq!(a.determinant(), -14);
+
+        let b = SquareMatrix::new([[6i64, 1, 1], [4, -2, 5], [2, 8, 7]]);
+        assert_eq!(b.determinant(), -306);
+        assert_eq!(b.transpose().determinant(), -306);
+        assert_eq!((a.clone() * a).determinant(), 196);
+    }
+
+    #[test]
+    fn test_determinant_with_row_swaps() {
+        let a = SquareMatrix::new([[0i64, 1, 0], [0, 0, 1], [1, 0, 0]]);
+        assert_eq!(a.determinant(), 1);
+
+        let b = SquareMatrix::new([[0i64, 2], [3, 4]]);
+        assert_eq!(b.determinant(), -6);
+
+        let c = SquareMatrix::new([[1i64, 2, 3, 4], [2, 4, 6, 9], [0, 1, 1, 1], [1, 1, 0, 2]]);
+        assert_eq!(c.determinant(), -2);
+    }
+
+    #[test]
+    fn test_determinant_singular() {
+        let a = SquareMatrix::new([[1i64, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(a.determinant(), 0);
+        assert_eq!(SquareMatrix::<i64, 3>::zero().determinant(), 0);
+        assert_eq!(SquareMatrix::<i64, 3>::one().determinant(), 1);
+        assert_eq!(SquareMatrix::<i64, 0>::new([]).determinant(), 1);
+    }
+
+    #[test]
+    fn test_characteristic_polynomial() {
+        let a = SquareMatrix::new([[2i64, -1, 0], [-1, 2, -1], [0, -1, 2]]);
+        let p = a.characteristic_polynomial();
+        assert_eq!(p, Polynomial::from_vector(vec![-4, 10, -6, 1]));
+        assert_eq!(p.coefficients()[2], -a.trace());
+        assert_eq!(p.coefficients()[0], -a.determinant());
+    }
+
+    #[test]
+    fn test_characteristic_polynomial_small() {
+        assert_eq!(
+            SquareMatrix::<i64, 0>::new([]).characteristic_polynomial(),
+            Polynomial::from_constant(1)
+        );
+        assert_eq!(
+            SquareMatrix::new([[7i64]]).characteristic_polynomial(),
+            Polynomial::from_vector(vec![-7, 1])
+        );
+        assert_eq!(
+            SquareMatrix::<i64, 3>::zero().characteristic_polynomial(),
+            Polynomial::x_pow(3)
+        );
+    }
+
+    #[test]
+    fn test_cayley_hamilton() {
+        let a = SquareMatrix::new([[1i64, 2, 0, -1], [3, 0, 1, 2], [-2, 1, 4, 0], [0, 5, -1, 1]]);
+        let p = a.characteristic_polynomial();
+        assert_eq!(p.degree(), 4);
+        assert!(p.eval(a).is_zero());
+
+        let b = SquareMatrix::new([
+            [RationalNumber::new(1, 2), r(3), r(0)],
+            [r(-1), RationalNumber::new(2, 3), r(1)],
+            [r(4), r(0), r(-2)],
+        ]);
+        let q = b.characteristic_polynomial();
+        assert!(q.eval(b.clone()).is_zero());
+        assert_eq!(q.coefficients()[0], -b.determinant());
+    }
+
+    #[test]
+    fn test_minimal_polynomial() {
+        // a Jordan block of size 2 for 1 and a Jordan block of size 1 for 1
+        let a = SquareMatrix::new([[r(1), r(1), r(0)], [r(0), r(1), r(0)], [r(0), r(0), r(1)]]);
+        let minimal = a.minimal_polynomial();
+        assert_eq!(minimal, Polynomial::from_vector(vec![r(1), r(-2), r(1)]));
+        assert!(a.annihilates(&minimal));
+        assert!(!a.annihilates(&Polynomial::from_vector(vec![r(-1), r(1)])));
+    }
+
+    #[test]
+    fn test_minimal_polynomial_divides_characteristic() {
+        let a = SquareMatrix::new([
+            [r(2), r(1), r(0), r(0)],
+            [r(0), r(2), r(0), r(0)],
+            [r(0), r(0), r(2), r(0)],
+            [r(0), r(0), r(0), RationalNumber::new(1, 2)],
+        ]);
+        let minimal = a.minimal_polynomial();
+        let characteristic = a.characteristic_polynomial();
+        assert_eq!(minimal.degree(), 3);
+        assert!(a.annihilates(&minimal));
+        assert!(characteristic.div_rem(&minimal).1.is_zero());
+    }
+
+    #[test]
+    fn test_minimal_polynomial_of_companion_matrix() {
+        // companion matrix of x^3 - 2x + 5: the minimal polynomial is the characteristic one
+        let a = SquareMatrix::new([[r(0), r(0), r(-5)], [r(1), r(0), r(2)], [r(0), r(1), r(0)]]);
+        assert_eq!(a.minimal_polynomial(), a.characteristic_polynomial());
+        assert_eq!(
+            a.minimal_polynomial(),
+            Polynomial::from_vector(vec![r(5), r(-2), r(0), r(1)])
+        );
+    }
+
+    #[test]
+    fn test_minimal_polynomial_scalar() {
+        let a = SquareMatrix::<RationalNumber, 3>::one() * r(4);
+        assert_eq!(
+            a.minimal_polynomial(),
+            Polynomial::from_vector(vec![r(-4), r(1)])
+        );
+        assert_eq!(
+            SquareMatrix::<RationalNumber, 2>::zero().minimal_polynomial(),
+            Polynomial::x()
+        );
+    }
+
+    #[test]
+    fn test_row_echelon_forms() {
+        let a = SquareMatrix::new([[r(0), r(2), r(4)], [r(1), r(1), r(1)], [r(2), r(4), r(6)]]);
+        assert_eq!(
+            a.row_echelon_form(),
+            SquareMatrix::new([[r(1), r(1), r(1)], [r(0), r(2), r(4)], [r(0), r(0), r(0)]])
+        );
+        assert_eq!(
+            a.reduced_row_echelon_form(),
+            SquareMatrix::new([[r(1), r(0), r(-1)], [r(0), r(1), r(2)], [r(0), r(0), r(0)]])
+        );
+        assert_eq!(a.rank(), 2);
+        assert_eq!(SquareMatrix::<RationalNumber, 3>::one().rank(), 3);
+        assert_eq!(SquareMatrix::<RationalNumber, 3>::zero().rank(), 0);
+    }
+
+    #[test]
+    fn test_inverse_matrices() {
+        let a = SquareMatrix::new([[r(2), r(1), r(0)], [r(0), r(1), r(3)], [r(1), r(0), r(1)]]);
+        let inverse = a.inverse().unwrap();
+        assert_eq!(a.clone() * inverse.clone(), SquareMatrix::one());
+        assert_eq!(inverse * a, SquareMatrix::one());
+
+        let singular = SquareMatrix::new([[r(1), r(2)], [r(2), r(4)]]);
+        assert_eq!(singular.inverse(), None);
+    }
+
+    #[test]
+    fn test_solve_unique() {
+        let a = SquareMatrix::new([[r(0), r(1), r(1)], [r(1), r(0), r(1)], [r(1), r(1), r(0)]]);
+        assert_eq!(
+            a.solve([r(5), r(4), r(3)]),
+            LinearSystemSolution::Unique([r(1), r(2), r(3)])
+        );
+    }
+
+    #[test]
+    fn test_solve_infinite() {
+        let a = SquareMatrix::new([[r(1), r(2), r(3)], [r(2), r(4), r(6)], [r(1), r(0), r(1)]]);
+        let b = [r(6), r(12), r(2)];
+        let LinearSystemSolution::Infinite { particular, kernel } = a.solve(b) else {
+            panic!("expected infinitely many solutions");
+        };
+        assert_eq!(kernel.len(), 1);
+        let mul = |x: [RationalNumber; 3]| -> [RationalNumber; 3] {
+            std::array::from_fn(|row| {
+                (0..3).fold(r(0), |sum, col| sum + a.data()[row][col] * x[col])
+            })
+        };
+        assert_eq!(mul(particular), b);
+        assert_eq!(mul(kernel[0]), [r(0); 3]);
+        assert!(kernel[0].iter().any(|it| !it.is_zero()));
+    }
+
+    #[test]
+    fn test_solve_inconsistent() {
+        let a = SquareMatrix::new([[r(1), r(2)], [r(2), r(4)]]);
+        assert_eq!(a.solve([r(1), r(1)]), LinearSystemSolution::Inconsistent);
+        let zero = SquareMatrix::<RationalNumber, 2>::zero();
+        assert_eq!(zero.solve([r(0), r(1)]), LinearSystemSolution::Inconsistent);
+    }
+
+    #[test]
+    fn test_lu_decomposition() {
+        let a = SquareMatrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]]);
+        let lu = a.lu();
+        assert_eq!(lu.permutation(), &[2, 0, 1]);
+
+        let permuted = SquareMatrix::new(lu.permutation().map(|row| a.data()[row]));
+        let product = lu.lower().clone() * lu.upper().clone();
+        for row in 0..3 {
+            assert_eq!(lu.lower().data()[row][row], 1.0);
+            for col in 0..3 {
+                assert!((product.data()[row][col] - permuted.data()[row][col]).abs() < 1e-12);
+                if col < row {
+                    assert_eq!(lu.upper().data()[row][col], 0.0);
+                }
+            }
+        }
+
+        assert!((lu.determinant() + 3.0).abs() < 1e-12);
+        let x = lu.solve([6.0, 15.0, 25.0]).unwrap();
+        for (value, expected) in x.iter().zip([1.0, 1.0, 1.0]) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+
+        let inverse = lu.inverse().unwrap();
+        let identity = a * inverse;
+        for row in 0..3 {
+            for col in 0..3 {
+                let expected = if row == col { 1.0 } else { 0.0 };
+                assert!((identity.data()[row][col] - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lu_decomposition_singular() {
+        let a = SquareMatrix::new([[1.0, 2.0], [2.0, 4.0]]);
+        let lu = a.lu();
+        assert!(lu.is_singular());
+        assert_eq!(lu.determinant(), 0.0);
+        assert_eq!(lu.solve([1.0, 2.0]), None);
+        assert_eq!(lu.inverse(), None);
+    }
+
     #[test]
     fn test_add_matrices_3x3() {
         let a = SquareMatrix::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
@@ -0,0 +1,275 @@
+#![forbid(unsafe_code)]
+//! # Matrix functions
+//!
+//! Analytic functions of `SquareMatrix<f64, N>`, computed by evaluating polynomial or rational
+//! approximants at the matrix with [`Polynomial::eval_matrix`].
+//!
+//! The exponential uses scaling and squaring with a diagonal Padé approximant, following
+//! Moler and Van Loan, "Nineteen dubious ways to compute the exponential of a matrix, twenty-five years later" (2003).
+
+use num::One;
+
+use super::SquareMatrix;
+use crate::polynomials::Polynomial;
+
+/// The degree of the numerator and the denominator of the Padé approximant of the exponential.
+const PADE_DEGREE: usize = 6;
+
+/// The norm below which the approximants are accurate to about the unit roundoff.
+/// The matrix is scaled down by a power of two until its norm is below this threshold.
+const SCALING_THRESHOLD: f64 = 0.5;
+
+/// The number of terms of the Taylor series of the sine and the cosine.
+const TAYLOR_TERMS: usize = 18;
+
+/// A truncated power series is accepted when its last terms are below this tolerance relative to its sum.
+const TAIL_TOLERANCE: f64 = f64::EPSILON;
+
+/// Returns the numerator `p` of the `(q, q)` Padé approximant `p(x) / p(-x)` of `e^x`.
+fn exp_pade_numerator(q: usize) -> Polynomial<f64> {
+    let mut coefficients = vec![1.0];
+    for k in 1..=q {
+        let previous = coefficients[k - 1];
+        coefficients.push(previous * (q - k + 1) as f64 / ((2 * q - k + 1) * k) as f64);
+    }
+    Polynomial::from_vector(coefficients)
+}
+
+impl<const N: usize> SquareMatrix<f64, N> {
+    /// Returns the 1-norm of the matrix, i.e. its largest absolute column sum.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::SquareMatrix;
+    /// let m = SquareMatrix::new([[1.0, -2.0], [-3.0, 4.0]]);
+    /// assert_eq!(m.norm_one(), 6.0);
+    /// ```
+    pub fn norm_one(&self) -> f64 {
+        (0..N)
+            .map(|col| (0..N).map(|row| self.data()[row][col].abs()).sum())
+            .fold(0.0, f64::max)
+    }
+
+    /// Returns the number of halvings `s` such that `A / 2^s` has a norm below the scaling threshold.
+    fn scaling_steps(&self) -> u32 {
+        assert!(
+            self.data().iter().flatten().all(|it| it.is_finite()),
+            "matrix entries must be finite"
+        );
+        let norm = self.norm_one();
+        let mut steps = 0;
+        while norm / 2f64.powi(steps as i32) > SCALING_THRESHOLD {
+            steps += 1;
+        }
+        steps
+    }
+
+    /// Returns the matrix exponential `e^A`.
+    /// The matrix is scaled by `2^-s` so that a Padé approximant is accurate,
+    /// and the result is squared `s` times.
+    /// # Panics
+    /// Panics if the matrix has non-finite entries.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::SquareMatrix;
+    /// let m = SquareMatrix::new([[0.0, 1.0], [0.0, 0.0]]);
+    /// let e = m.exp();
+    /// assert!((e - SquareMatrix::new([[1.0, 1.0], [0.0, 1.0]])).norm_one() < 1e-15);
+    /// ```
+    pub fn exp(&self) -> Self {
+        let steps = self.scaling_steps();
+        let scaled = self.clone() * 2f64.powi(-(steps as i32));
+
+        let numerator = exp_pade_numerator(PADE_DEGREE);
+        let p = numerator.eval_matrix(scaled.clone());
+        let q = numerator.eval_matrix(-scaled);
+        // q(A) is well-conditioned for a small norm of A, as q(x) = p(-x) has no roots near zero
+        let mut result = q
+            .lu()
+            .inverse()
+            .expect("the Padé denominator is invertible for scaled matrices")
+            * p;
+
+        for _ in 0..steps {
+            result = result.clone() * result;
+        }
+        result
+    }
+
+    /// Returns the matrix sine and cosine `(sin A, cos A)`.
+    /// The matrix is scaled by `2^-s` so that the Taylor series converge quickly,
+    /// and the double angle formulas `sin 2X = 2 sin X cos X`, `cos 2X = 2 cos^2 X - I` are applied `s` times.
+    /// # Panics
+    /// Panics if the matrix has non-finite entries.
+    pub fn sin_cos(&self) -> (Self, Self) {
+        let steps = self.scaling_steps();
+        let scaled = self.clone() * 2f64.powi(-(steps as i32));
+
+        // sin x = x s(x^2) and cos x = c(x^2), with the Taylor series s and c
+        let mut sin_coefficients = Vec::new();
+        let mut cos_coefficients = Vec::new();
+        let mut factorial = 1.0;
+        for k in 0..TAYLOR_TERMS {
+            let sign = if k % 2 == 0 { 1.0 } else { -1.0 };
+            cos_coefficients.push(sign / factorial);
+            factorial *= (2 * k + 1) as f64;
+            sin_coefficients.push(sign / factorial);
+            factorial *= (2 * k + 2) as f64;
+        }
+        let square = scaled.clone() * scaled.clone();
+        let mut sin =
+            scaled * Polynomial::from_vector(sin_coefficients).eval_matrix(square.clone());
+        let mut cos = Polynomial::from_vector(cos_coefficients).eval_matrix(square);
+
+        for _ in 0..steps {
+            let double_sin = sin.clone() * cos.clone() * 2.0;
+            cos = cos.clone() * cos * 2.0 - Self::one();
+            sin = double_sin;
+        }
+        (sin, cos)
+    }
+
+    /// Returns the matrix sine `sin A`, see [`SquareMatrix::sin_cos`].
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::SquareMatrix;
+    /// let m = SquareMatrix::new([[std::f64::consts::FRAC_PI_2, 0.0], [0.0, 0.0]]);
+    /// assert!((m.sin() - SquareMatrix::new([[1.0, 0.0], [0.0, 0.0]])).norm_one() < 1e-14);
+    /// ```
+    pub fn sin(&self) -> Self {
+        self.sin_cos().0
+    }
+
+    /// Returns the matrix cosine `cos A`, see [`SquareMatrix::sin_cos`].
+    pub fn cos(&self) -> Self {
+        self.sin_cos().1
+    }
+
+    /// Returns `f(A)` for the analytic function `f(x) = sum a_k x^k`, truncated after the term of degree `degree`.
+    /// The coefficient `a_k` is `coefficient(k)`.
+    ///
+    /// A general power series has no doubling formula, so no scaling is applied, and the truncation is checked
+    /// a posteriori instead: the norms of the last two terms `a_k A^k` estimate the tail of the series,
+    /// two so that series with only even or only odd terms are covered.
+    /// `None` is returned if they are not negligible compared to the sum, i.e. if the series has not converged.
+    /// The exponential, sine and cosine, which can be scaled, have their own methods.
+    /// # Panics
+    /// Panics if `degree` is zero.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::SquareMatrix;
+    /// let m = SquareMatrix::new([[0.1, 0.0], [0.2, -0.1]]);
+    /// let geometric = m.analytic(|_| 1.0, 40).unwrap();
+    /// let inverse = (SquareMatrix::new([[1.0, 0.0], [0.0, 1.0]]) - m).lu().inverse().unwrap();
+    /// assert!((geometric - inverse).norm_one() < 1e-14);
+    /// // the geometric series diverges for the spectral radius 2
+    /// let m = SquareMatrix::new([[2.0, 0.0], [0.0, 0.5]]);
+    /// assert!(m.analytic(|_| 1.0, 40).is_none());
+    /// ```
+    pub fn analytic(&self, coefficient: impl Fn(usize) -> f64, degree: usize) -> Option<Self> {
+        assert!(degree > 0, "the series needs at least two terms");
+        let coefficients: Vec<f64> = (0..=degree).map(coefficient).collect();
+        let result = Polynomial::from_vector(coefficients.clone()).eval_matrix(self.clone());
+
+        let power = Polynomial::<f64>::x_pow(degree - 1).eval_matrix(self.clone());
+        let tail = coefficients[degree - 1].abs() * power.norm_one()
+            + coefficients[degree].abs() * (power * self.clone()).norm_one();
+        (tail <= TAIL_TOLERANCE * result.norm_one()).then_some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn distance<const N: usize>(a: &SquareMatrix<f64, N>, b: &SquareMatrix<f64, N>) -> f64 {
+        (a.clone() - b.clone()).norm_one() / b.norm_one().max(1.0)
+    }
+
+    #[test]
+    fn test_exp_pade_numerator() {
+        // (1 + x/2 + x^2/12) / (1 - x/2 + x^2/12)
+        assert_eq!(
+            exp_pade_numerator(2),
+            Polynomial::from_vector(vec![1.0, 0.5, 1.0 / 12.0])
+        );
+    }
+
+    #[test]
+    fn test_exp_diagonal() {
+        let m = SquareMatrix::new([[1.0, 0.0, 0.0], [0.0, -2.0, 0.0], [0.0, 0.0, 10.0]]);
+        let expected = SquareMatrix::new([
+            [1f64.exp(), 0.0, 0.0],
+            [0.0, (-2f64).exp(), 0.0],
+            [0.0, 0.0, 10f64.exp()],
+        ]);
+        assert!(distance(&m.exp(), &expected) < 1e-13);
+    }
+
+    #[test]
+    fn test_exp_rotation() {
+        let t: f64 = 3.0;
+        let m = SquareMatrix::new([[0.0, -t], [t, 0.0]]);
+        let expected = SquareMatrix::new([[t.cos(), -t.sin()], [t.sin(), t.cos()]]);
+        assert!(distance(&m.exp(), &expected) < 1e-14);
+    }
+
+    #[test]
+    fn test_exp_inverse() {
+        let m = SquareMatrix::new([[0.5, 2.0, -1.0], [1.0, -3.0, 0.0], [0.3, 0.0, 1.5]]);
+        let product = m.exp() * (-m).exp();
+        assert!(distance(&product, &SquareMatrix::one()) < 1e-13);
+    }
+
+    #[test]
+    fn test_exp_zero() {
+        let m = SquareMatrix::new([[0.0; 2]; 2]);
+        assert_eq!(m.exp(), SquareMatrix::one());
+    }
+
+    #[test]
+    fn test_sin_cos() {
+        let m = SquareMatrix::new([[0.5, 2.0, -1.0], [1.0, -3.0, 0.0], [0.3, 0.0, 1.5]]);
+        let (sin, cos) = m.sin_cos();
+        let pythagoras = sin.clone() * sin + cos.clone() * cos;
+        assert!(distance(&pythagoras, &SquareMatrix::one()) < 1e-12);
+
+        let d = SquareMatrix::new([[1.0, 0.0], [0.0, 7.0]]);
+        let expected = SquareMatrix::new([[1f64.cos(), 0.0], [0.0, 7f64.cos()]]);
+        assert!(distance(&d.cos(), &expected) < 1e-13);
+        let expected = SquareMatrix::new([[1f64.sin(), 0.0], [0.0, 7f64.sin()]]);
+        assert!(distance(&d.sin(), &expected) < 1e-13);
+    }
+
+    #[test]
+    fn test_analytic_exp_series() {
+        let m = SquareMatrix::new([[0.1, 0.2], [-0.3, 0.05]]);
+        let mut factorials = vec![1.0];
+        for k in 1..20 {
+            factorials.push(factorials[k - 1] * k as f64);
+        }
+        let series = m.analytic(|k| 1.0 / factorials[k], 19).unwrap();
+        assert!(distance(&series, &m.exp()) < 1e-15);
+    }
+
+    #[test]
+    fn test_analytic_non_convergence() {
+        // the partial sums of the geometric series oscillate for a rotation
+        let m = SquareMatrix::new([[0.0, 1.0], [-1.0, 0.0]]);
+        assert!(m.analytic(|_| 0.5, 20).is_none());
+        // too few terms of the exponential series
+        let m = SquareMatrix::new([[1.0, 2.0], [0.0, -1.0]]);
+        assert!(m
+            .analytic(|k| if k == 3 { 1.0 / 6.0 } else { 1.0 }, 3)
+            .is_none());
+        // a nilpotent matrix truncates the series exactly
+        let m = SquareMatrix::new([[0.0, 3.0], [0.0, 0.0]]);
+        let inverse = m.analytic(|_| 1.0, 4).unwrap();
+        assert_eq!(inverse, SquareMatrix::new([[1.0, 3.0], [0.0, 1.0]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "matrix entries must be finite")]
+    fn test_exp_non_finite() {
+        SquareMatrix::new([[f64::NAN]]).exp();
+    }
+}
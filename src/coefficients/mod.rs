@@ -1,9 +1,18 @@
 #![forbid(unsafe_code)]
 
+pub mod complex_numbers;
+pub mod dynamic_matrices;
+pub mod gaussian_integers;
+pub mod intervals;
 pub mod matrices;
+pub mod matrix_functions;
 pub mod natural_numbers;
 pub mod rational_numbers;
 
-pub use matrices::SquareMatrix;
+pub use complex_numbers::Complex;
+pub use dynamic_matrices::Matrix;
+pub use gaussian_integers::GaussianInteger;
+pub use intervals::Interval;
+pub use matrices::{LinearSystemSolution, LuDecomposition, SquareMatrix};
 pub use natural_numbers::NaturalNumber;
 pub use rational_numbers::RationalNumber;
@@ -97,6 +97,20 @@ impl std::ops::Mul for RationalNumber {
     }
 }
 
+impl std::ops::Div for RationalNumber {
+    type Output = Self;
+
+    /// # Panics
+    /// Panics if `other` is zero.
+    fn div(self, other: Self) -> Self {
+        assert!(other.numerator != 0, "division by zero");
+        Self::new(
+            self.numerator * other.denominator,
+            self.denominator * other.numerator,
+        )
+    }
+}
+
 impl Zero for RationalNumber {
     fn zero() -> Self {
         Self {
@@ -147,6 +161,20 @@ mod tests {
         assert_eq!(c, RationalNumber::new(1, 2));
     }
 
+    #[test]
+    fn test_div_rational_numbers() {
+        let a = RationalNumber::new(3, 4);
+        let b = RationalNumber::new(-1, 2);
+        let c = a / b;
+        assert_eq!(c, RationalNumber::new(-3, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero")]
+    fn test_div_by_zero_rational_numbers() {
+        let _ = RationalNumber::new(1, 2) / RationalNumber::zero();
+    }
+
     #[test]
     fn test_zero_rational_numbers() {
         let a = RationalNumber::zero();
@@ -0,0 +1,34 @@
+#![forbid(unsafe_code)]
+//! # Floating point helpers
+//!
+//! Error-free transformations of `f64` operations, shared by the interval arithmetic
+//! and the compensated evaluation of polynomials.
+
+/// Returns `(a + b, e)` such that `a + b = (a + b) + e` exactly.
+pub(crate) fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    (sum, (a - a_virtual) + (b - b_virtual))
+}
+
+/// Returns `(a * b, e)` such that `a * b = (a * b) + e` exactly.
+pub(crate) fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let product = a * b;
+    (product, a.mul_add(b, -product))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_free_transformations() {
+        let (sum, error) = two_sum(1.0, 1e-20);
+        assert_eq!((sum, error), (1.0, 1e-20));
+        let a = 1.0 + f64::EPSILON;
+        let (product, error) = two_product(a, a);
+        assert_eq!(product, 1.0 + 2.0 * f64::EPSILON);
+        assert_eq!(error, f64::EPSILON * f64::EPSILON);
+    }
+}
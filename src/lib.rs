@@ -1,4 +1,5 @@
 #![forbid(unsafe_code)]
 
 pub mod coefficients;
+mod float;
 pub mod polynomials;
@@ -0,0 +1,465 @@
+#![forbid(unsafe_code)]
+//! # Function approximation
+//!
+//! Polynomial approximations of a function `f64 -> f64` on an interval:
+//! interpolation at the Chebyshev nodes, which is within a small factor of the best approximation,
+//! and the Remez exchange algorithm, which converges to the minimax polynomial.
+//!
+//! The computations are carried out in the Chebyshev basis on `[-1, 1]`,
+//! and only the final result is converted to the monomial basis.
+
+use std::f64::consts::PI;
+
+use num::Zero;
+
+use super::Polynomial;
+use crate::coefficients::Interval;
+
+/// The maximal number of exchanges of the Remez algorithm.
+const REMEZ_ITERATIONS: usize = 50;
+
+/// The Remez algorithm stops once the extrema of the error agree with the levelled error to this relative tolerance.
+const REMEZ_TOLERANCE: f64 = 1e-9;
+
+/// The number of grid points per degree used to locate the extrema of the error.
+const GRID_POINTS_PER_DEGREE: usize = 64;
+
+/// The number of steps of the golden section search refining every extremum of the error.
+const GOLDEN_SECTION_STEPS: usize = 60;
+
+/// A polynomial approximation of a function on an interval, together with its maximum error.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Approximation {
+    polynomial: Polynomial<f64>,
+    max_error: f64,
+}
+
+impl Approximation {
+    /// Returns the approximating polynomial.
+    pub fn polynomial(&self) -> &Polynomial<f64> {
+        &self.polynomial
+    }
+
+    /// Returns the maximum of `|f(x) - p(x)|` over the interval.
+    /// It is located on a dense grid and refined with a golden section search around every extremum.
+    pub fn max_error(&self) -> f64 {
+        self.max_error
+    }
+}
+
+/// Maps `x` from `interval` to `[-1, 1]`.
+fn to_unit(interval: Interval, x: f64) -> f64 {
+    (2.0 * x - interval.lo() - interval.hi()) / interval.width()
+}
+
+/// Maps `t` from `[-1, 1]` to `interval`.
+fn from_unit(interval: Interval, t: f64) -> f64 {
+    interval.midpoint() + 0.5 * interval.width() * t
+}
+
+/// Returns `T_0(t), ..., T_n(t)`.
+fn chebyshev_values(t: f64, n: usize) -> Vec<f64> {
+    let mut values = vec![1.0, t];
+    for j in 2..=n {
+        values.push(2.0 * t * values[j - 1] - values[j - 2]);
+    }
+    values.truncate(n + 1);
+    values
+}
+
+/// Evaluates `sum c_j T_j(t)` with Clenshaw's recurrence.
+fn clenshaw(coefficients: &[f64], t: f64) -> f64 {
+    let (mut b1, mut b2) = (0.0, 0.0);
+    for &c in coefficients.iter().skip(1).rev() {
+        (b1, b2) = (2.0 * t * b1 - b2 + c, b1);
+    }
+    t * b1 - b2 + coefficients[0]
+}
+
+/// Converts `sum c_j T_j(t)`, with `t` the image of `x` in `[-1, 1]`, to the monomial basis in `x`.
+fn to_polynomial(coefficients: &[f64], interval: Interval) -> Polynomial<f64> {
+    let t = Polynomial::from_vector(vec![
+        -(interval.lo() + interval.hi()) / interval.width(),
+        2.0 / interval.width(),
+    ]);
+    let mut previous = Polynomial::from_constant(1.0);
+    let mut current = t.clone();
+    let mut sum = previous.clone() * coefficients[0];
+    for &c in &coefficients[1..] {
+        sum = sum + current.clone() * c;
+        let next = t.clone() * current.clone() * 2.0 - previous;
+        previous = std::mem::replace(&mut current, next);
+    }
+    sum
+}
+
+/// Returns the argument and the value of the maximum of `g` on `[lo, hi]`,
+/// assuming that `g` is unimodal there.
+fn golden_section_maximum(g: impl Fn(f64) -> f64, mut lo: f64, mut hi: f64) -> (f64, f64) {
+    let ratio = 0.5 * (5f64.sqrt() - 1.0);
+    let (mut x1, mut x2) = (hi - ratio * (hi - lo), lo + ratio * (hi - lo));
+    let (mut g1, mut g2) = (g(x1), g(x2));
+    for _ in 0..GOLDEN_SECTION_STEPS {
+        if g1 < g2 {
+            lo = x1;
+            (x1, g1) = (x2, g2);
+            x2 = lo + ratio * (hi - lo);
+            g2 = g(x2);
+        } else {
+            hi = x2;
+            (x2, g2) = (x1, g1);
+            x1 = hi - ratio * (hi - lo);
+            g1 = g(x1);
+        }
+    }
+    if g1 > g2 {
+        (x1, g1)
+    } else {
+        (x2, g2)
+    }
+}
+
+/// Splits the interval into the runs where the error has a constant sign,
+/// and returns the point of largest `|error|` of every run with the error there.
+/// Consecutive points therefore alternate in sign.
+fn error_extrema(error: &dyn Fn(f64) -> f64, interval: Interval, degree: usize) -> Vec<(f64, f64)> {
+    // the grid is denser near the ends, like the extrema of the error usually are
+    let size = GRID_POINTS_PER_DEGREE * (degree + 2);
+    let grid: Vec<f64> = (0..=size)
+        .map(|i| from_unit(interval, -(PI * i as f64 / size as f64).cos()))
+        .collect();
+    let values: Vec<f64> = grid.iter().map(|&x| error(x)).collect();
+
+    let mut extrema: Vec<(f64, f64)> = Vec::new();
+    let mut best: Vec<usize> = Vec::new();
+    for (i, &value) in values.iter().enumerate() {
+        match extrema.last_mut() {
+            Some(last) if value.is_zero() || value.signum() == last.1.signum() => {
+                if value.abs() > last.1.abs() {
+                    *last = (grid[i], value);
+                    *best.last_mut().unwrap() = i;
+                }
+            }
+            _ => {
+                extrema.push((grid[i], value));
+                best.push(i);
+            }
+        }
+    }
+
+    for (extremum, i) in extrema.iter_mut().zip(best) {
+        let sign = extremum.1.signum();
+        let (lo, hi) = (grid[i.saturating_sub(1)], grid[(i + 1).min(size)]);
+        let (x, value) = golden_section_maximum(|x| sign * error(x), lo, hi);
+        if value > extremum.1.abs() {
+            *extremum = (x, sign * value);
+        }
+    }
+    extrema
+}
+
+/// Solves the square linear system `a x = b` with Gaussian elimination and partial pivoting.
+/// Returns `None` if the system is singular.
+fn solve(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+    for k in 0..n {
+        let pivot = (k..n).max_by(|&i, &j| a[i][k].abs().total_cmp(&a[j][k].abs()))?;
+        if a[pivot][k].is_zero() {
+            return None;
+        }
+        a.swap(k, pivot);
+        b.swap(k, pivot);
+        let (upper, lower) = a.split_at_mut(k + 1);
+        let (b_upper, b_lower) = b.split_at_mut(k + 1);
+        for (row, b_i) in lower.iter_mut().zip(b_lower) {
+            let factor = row[k] / upper[k][k];
+            for (a_ij, a_kj) in row[k..].iter_mut().zip(&upper[k][k..]) {
+                *a_ij -= factor * a_kj;
+            }
+            *b_i -= factor * b_upper[k];
+        }
+    }
+    let mut x = vec![0.0; n];
+    for k in (0..n).rev() {
+        let sum: f64 = (k + 1..n).map(|j| a[k][j] * x[j]).sum();
+        x[k] = (b[k] - sum) / a[k][k];
+    }
+    Some(x)
+}
+
+/// Returns the maximum of `|error|` over its extrema.
+fn max_error(error: &dyn Fn(f64) -> f64, interval: Interval, degree: usize) -> f64 {
+    error_extrema(error, interval, degree)
+        .iter()
+        .map(|extremum| extremum.1.abs())
+        .fold(0.0, f64::max)
+}
+
+impl Polynomial<f64> {
+    /// Returns the polynomial of degree `degree` interpolating `f` at the Chebyshev nodes of the interval,
+    /// the images of the roots of `T_(degree+1)`.
+    /// Its error is at most a factor `2 + 2 log(degree + 1) / pi` above the one of the minimax polynomial.
+    /// # Panics
+    /// Panics if the interval is a single point.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::Interval;
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let approximation = Polynomial::chebyshev_interpolation(f64::exp, Interval::new(0.0, 1.0), 8);
+    /// assert!(approximation.max_error() < 1e-9);
+    /// assert!((approximation.polynomial().eval(0.3) - 0.3f64.exp()).abs() < 1e-9);
+    /// ```
+    pub fn chebyshev_interpolation(
+        f: impl Fn(f64) -> f64,
+        interval: Interval,
+        degree: usize,
+    ) -> Approximation {
+        assert!(
+            interval.width() > 0.0,
+            "the interval must have a positive width"
+        );
+        let n = degree + 1;
+        let angles: Vec<f64> = (0..n).map(|k| PI * (k as f64 + 0.5) / n as f64).collect();
+        let values: Vec<f64> = angles
+            .iter()
+            .map(|angle| f(from_unit(interval, angle.cos())))
+            .collect();
+        // the discrete orthogonality of the Chebyshev polynomials at their nodes gives the coefficients
+        let mut coefficients: Vec<f64> = (0..n)
+            .map(|j| {
+                let sum: f64 = angles
+                    .iter()
+                    .zip(&values)
+                    .map(|(angle, value)| value * (j as f64 * angle).cos())
+                    .sum();
+                2.0 * sum / n as f64
+            })
+            .collect();
+        coefficients[0] *= 0.5;
+
+        let polynomial = to_polynomial(&coefficients, interval);
+        let max_error = max_error(&|x| f(x) - polynomial.eval(x), interval, degree);
+        Approximation {
+            polynomial,
+            max_error,
+        }
+    }
+
+    /// Returns the minimax polynomial of degree `degree` of `f` on the interval,
+    /// i.e. the one with the smallest maximum error, computed with the Remez exchange algorithm.
+    ///
+    /// The reference starts at the extrema of the Chebyshev polynomial `T_(degree+1)`, and restarts once
+    /// from an asymmetric one if the levelled error collapses to zero. At every step the polynomial
+    /// whose error equioscillates on the reference is computed, and the reference is replaced by
+    /// the alternating extrema of its error. The best polynomial found is returned, even if the iteration
+    /// has not converged, e.g. for a function which is not continuous.
+    /// # Panics
+    /// Panics if the interval is a single point.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::Interval;
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let interval = Interval::new(0.0, 1.0);
+    /// let minimax = Polynomial::minimax(f64::exp, interval, 4);
+    /// let chebyshev = Polynomial::chebyshev_interpolation(f64::exp, interval, 4);
+    /// assert!(minimax.max_error() < chebyshev.max_error());
+    /// ```
+    pub fn minimax(f: impl Fn(f64) -> f64, interval: Interval, degree: usize) -> Approximation {
+        assert!(
+            interval.width() > 0.0,
+            "the interval must have a positive width"
+        );
+        let n = degree;
+        // the extrema of T_(n+1), and the first n + 2 of the n + 3 extrema of T_(n+2)
+        let extrema_reference = |order: usize| -> Vec<f64> {
+            (0..n + 2)
+                .map(|i| from_unit(interval, -(PI * i as f64 / order as f64).cos()))
+                .collect()
+        };
+        let mut reference = extrema_reference(n + 1);
+        let mut restarted = false;
+
+        let mut best: Option<(Vec<f64>, f64)> = None;
+        for _ in 0..REMEZ_ITERATIONS {
+            // sum c_j T_j(x_i) + (-1)^i E = f(x_i) on the reference
+            let rows = reference
+                .iter()
+                .enumerate()
+                .map(|(i, &x)| {
+                    let mut row = chebyshev_values(to_unit(interval, x), n);
+                    row.push(if i % 2 == 0 { 1.0 } else { -1.0 });
+                    row
+                })
+                .collect();
+            let Some(solution) = solve(rows, reference.iter().map(|&x| f(x)).collect()) else {
+                break;
+            };
+            let level = solution[n + 1].abs();
+            let coefficients = solution[..=n].to_vec();
+
+            let error = |x: f64| f(x) - clenshaw(&coefficients, to_unit(interval, x));
+            let mut extrema = error_extrema(&error, interval, n);
+            let max = extrema
+                .iter()
+                .map(|extremum| extremum.1.abs())
+                .fold(0.0, f64::max);
+            let converged = max - level <= REMEZ_TOLERANCE * max;
+            if best.as_ref().is_none_or(|(_, error)| max < *error) {
+                best = Some((coefficients, max));
+            }
+            // a symmetric reference levels the error of an even function at an even degree,
+            // or of an odd function at an odd degree, to zero, since its minimax polynomial is also the one
+            // of the next degree and equioscillates on n + 3 points: restart from an asymmetric reference
+            let collapsed = level <= REMEZ_TOLERANCE * max || extrema.len() < n + 2;
+            if collapsed && !restarted {
+                restarted = true;
+                reference = extrema_reference(n + 2);
+                continue;
+            }
+            if converged || extrema.len() < n + 2 {
+                break;
+            }
+
+            // keep n + 2 alternating extrema, dropping the smallest ones
+            while extrema.len() > n + 2 {
+                let last = extrema.len() - 1;
+                if (extrema.len() - n) % 2 == 1 {
+                    // an odd excess: one of the ends goes
+                    if extrema[0].1.abs() < extrema[last].1.abs() {
+                        extrema.remove(0);
+                    } else {
+                        extrema.pop();
+                    }
+                } else {
+                    // an even excess: removing two neighbours keeps the alternation
+                    let i = (0..=last)
+                        .min_by(|&i, &j| extrema[i].1.abs().total_cmp(&extrema[j].1.abs()))
+                        .unwrap();
+                    let j = if i == 0 {
+                        1
+                    } else if i == last || extrema[i - 1].1.abs() < extrema[i + 1].1.abs() {
+                        i - 1
+                    } else {
+                        i + 1
+                    };
+                    extrema.remove(i.max(j));
+                    extrema.remove(i.min(j));
+                }
+            }
+            reference = extrema.iter().map(|extremum| extremum.0).collect();
+        }
+
+        let coefficients = best.expect("the first reference is never singular").0;
+        let polynomial = to_polynomial(&coefficients, interval);
+        let max_error = max_error(&|x| f(x) - polynomial.eval(x), interval, degree);
+        Approximation {
+            polynomial,
+            max_error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chebyshev_helpers() {
+        assert_eq!(chebyshev_values(0.5, 3), vec![1.0, 0.5, -0.5, -1.0]);
+        assert_eq!(chebyshev_values(0.5, 0), vec![1.0]);
+        assert_eq!(clenshaw(&[1.0, 2.0, 3.0], 0.5), 1.0 + 1.0 - 1.5);
+        // 1 + 2 T_1(t) + 3 T_2(t) with t = x - 1 on [0, 2]
+        let p = to_polynomial(&[1.0, 2.0, 3.0], Interval::new(0.0, 2.0));
+        assert_eq!(p, Polynomial::from_vector(vec![2.0, -10.0, 6.0]));
+    }
+
+    #[test]
+    fn test_chebyshev_interpolation_of_polynomial() {
+        let p = Polynomial::from_vector(vec![1.0, -2.0, 0.5, 3.0]);
+        let approximation =
+            Polynomial::chebyshev_interpolation(|x| p.eval(x), Interval::new(-2.0, 3.0), 3);
+        for (c, e) in approximation
+            .polynomial()
+            .coefficients()
+            .iter()
+            .zip(p.coefficients())
+        {
+            assert!((c - e).abs() < 1e-12);
+        }
+        assert!(approximation.max_error() < 1e-12);
+    }
+
+    #[test]
+    fn test_chebyshev_interpolation_error() {
+        let approximation =
+            Polynomial::chebyshev_interpolation(f64::sin, Interval::new(0.0, PI), 10);
+        let error = (0..=1000)
+            .map(|i| {
+                let x = PI * i as f64 / 1000.0;
+                (x.sin() - approximation.polynomial().eval(x)).abs()
+            })
+            .fold(0.0, f64::max);
+        assert!(approximation.max_error() >= error);
+        assert!(approximation.max_error() < 1e-8);
+    }
+
+    #[test]
+    fn test_minimax_of_abs() {
+        // the best linear approximation of x^2 on [-1, 1] is 1/2 with the error 1/2,
+        // and the best cubic one of |x| is 1/8 + x^2 with the error 1/8
+        let minimax = Polynomial::minimax(|x| x * x, Interval::new(-1.0, 1.0), 1);
+        assert!((minimax.max_error() - 0.5).abs() < 1e-9);
+        assert!((minimax.polynomial().eval(0.3) - 0.5).abs() < 1e-9);
+        let minimax = Polynomial::minimax(f64::abs, Interval::new(-1.0, 1.0), 3);
+        assert!((minimax.max_error() - 0.125).abs() < 1e-9);
+        let coefficients = minimax.polynomial().coefficients();
+        assert!((coefficients[0] - 0.125).abs() < 1e-9);
+        assert!(coefficients[1].abs() < 1e-9);
+        assert!((coefficients[2] - 1.0).abs() < 1e-9);
+        assert!(coefficients[3].abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_minimax_equioscillates() {
+        let interval = Interval::new(0.0, 2.0);
+        let f = |x: f64| (1.0 + x).ln();
+        let minimax = Polynomial::minimax(f, interval, 5);
+        let chebyshev = Polynomial::chebyshev_interpolation(f, interval, 5);
+        assert!(minimax.max_error() < chebyshev.max_error());
+        let error = |x: f64| f(x) - minimax.polynomial().eval(x);
+        let extrema = error_extrema(&error, interval, 5);
+        assert_eq!(extrema.len(), 7);
+        for (_, value) in extrema {
+            assert!((value.abs() - minimax.max_error()).abs() < 1e-6 * minimax.max_error());
+        }
+    }
+
+    #[test]
+    fn test_minimax_of_even_function_at_even_degree() {
+        // cos has the same minimax polynomial at the degrees 4 and 5, which equioscillates on 7 points
+        let interval = Interval::new(-1.0, 1.0);
+        let minimax = Polynomial::minimax(f64::cos, interval, 4);
+        assert!(minimax.max_error() < 5e-5);
+        let next = Polynomial::minimax(f64::cos, interval, 5);
+        assert!((minimax.max_error() - next.max_error()).abs() < 1e-9 * next.max_error());
+    }
+
+    #[test]
+    fn test_minimax_never_loses_to_chebyshev_interpolation() {
+        let interval = Interval::new(-1.0, 1.0);
+        let functions: [fn(f64) -> f64; 4] = [f64::cos, f64::sin, f64::abs, |x| x.abs().sqrt()];
+        for f in functions {
+            for degree in [3, 4, 5, 6, 10] {
+                let minimax = Polynomial::minimax(f, interval, degree);
+                let chebyshev = Polynomial::chebyshev_interpolation(f, interval, degree);
+                assert!(minimax.max_error() <= chebyshev.max_error());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the interval must have a positive width")]
+    fn test_degenerate_interval() {
+        Polynomial::minimax(f64::exp, Interval::point(1.0), 3);
+    }
+}
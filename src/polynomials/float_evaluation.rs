@@ -0,0 +1,349 @@
+#![forbid(unsafe_code)]
+//! # Evaluation of `f64` polynomials
+//!
+//! The batch routines run Horner's scheme on several points at once,
+//! keeping one accumulator per lane so that the compiler can vectorize the inner loop.
+//!
+//! The compensated Horner scheme follows Graillat, Langlois and Louvet,
+//! "Compensated Horner Scheme" (2005), and "How to ensure a faithful polynomial evaluation
+//! with the compensated Horner algorithm" (2007) for the running error bound.
+
+use super::Polynomial;
+use crate::coefficients::Complex;
+use crate::float::{two_product, two_sum};
+
+/// The number of points evaluated together by the batch routines.
+const LANES: usize = 8;
+
+/// The unit roundoff of `f64`.
+const UNIT_ROUNDOFF: f64 = f64::EPSILON / 2.0;
+
+/// `gamma(k) = k u / (1 - k u)`, the usual bound on the relative error of `k` roundings.
+fn gamma(k: usize) -> f64 {
+    let ku = k as f64 * UNIT_ROUNDOFF;
+    ku / (1.0 - ku)
+}
+
+impl Polynomial<f64> {
+    /// Evaluates the polynomial at `x` using Estrin's scheme.
+    /// Blocks of four coefficients are evaluated independently and then combined with Horner's scheme in `x^4`,
+    /// which shortens the dependency chain of plain Horner's scheme.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let p = Polynomial::from_vector(vec![1.0, 2.0, 3.0]); // 1 + 2x + 3x^2
+    /// assert_eq!(p.eval_estrin(2.0), 17.0);
+    /// ```
+    pub fn eval_estrin(&self, x: f64) -> f64 {
+        let x2 = x * x;
+        let x4 = x2 * x2;
+        let mut result = 0.0;
+        for block in self.coefficients().chunks(4).rev() {
+            let coeff = |it: usize| block.get(it).copied().unwrap_or(0.0);
+            let low = coeff(0) + coeff(1) * x;
+            let high = coeff(2) + coeff(3) * x;
+            result = result * x4 + (low + high * x2);
+        }
+        result
+    }
+
+    /// Evaluates the polynomial and its derivative at `x` in a single Horner pass.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let p = Polynomial::from_vector(vec![1.0, 2.0, 3.0]); // 1 + 2x + 3x^2
+    /// assert_eq!(p.eval_with_derivative(2.0), (17.0, 14.0));
+    /// ```
+    pub fn eval_with_derivative(&self, x: f64) -> (f64, f64) {
+        let mut value = 0.0;
+        let mut derivative = 0.0;
+        for &coeff in self.coefficients().iter().rev() {
+            derivative = derivative * x + value;
+            value = value * x + coeff;
+        }
+        (value, derivative)
+    }
+
+    /// Evaluates the polynomial at `x` with the compensated Horner scheme.
+    /// The rounding errors of Horner's scheme are computed exactly with error-free transformations
+    /// and added back, so the result is as accurate as if it had been computed in twice the working precision.
+    /// Returns the value together with a bound on its absolute error.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// // (x - 1)^3 expanded, ill-conditioned near its root
+    /// let p = Polynomial::from_vector(vec![-1.0, 3.0, -3.0, 1.0]);
+    /// let x = 1.0 + 1e-5;
+    /// let (value, error_bound) = p.eval_compensated(x);
+    /// let exact = (x - 1.0) * (x - 1.0) * (x - 1.0);
+    /// assert!((value - exact).abs() <= error_bound);
+    /// ```
+    pub fn eval_compensated(&self, x: f64) -> (f64, f64) {
+        let coefficients = self.coefficients();
+        let degree = self.degree();
+        let mut value = coefficients[coefficients.len() - 1];
+        let mut correction = 0.0;
+        let mut correction_bound = 0.0;
+        for &coeff in coefficients.iter().rev().skip(1) {
+            let (product, product_error) = two_product(value, x);
+            let (sum, sum_error) = two_sum(product, coeff);
+            value = sum;
+            correction = correction * x + (product_error + sum_error);
+            correction_bound = correction_bound * x.abs() + (product_error.abs() + sum_error.abs());
+        }
+
+        let result = value + correction;
+        let u = UNIT_ROUNDOFF;
+        let error_bound = (u * result.abs()
+            + (gamma(4 * degree + 2) * correction_bound + 2.0 * u * u * result.abs()))
+            / (1.0 - 2.0 * u);
+        (result, error_bound.next_up())
+    }
+
+    /// Returns the condition number of evaluating the polynomial at `x`,
+    /// i.e. `sum |a_i| |x|^i / |p(x)|`.
+    /// Plain Horner's scheme loses about `log10` of this many decimal digits,
+    /// [`Polynomial::eval_compensated`] loses about `log10` of this many digits beyond 32.
+    pub fn eval_condition_number(&self, x: f64) -> f64 {
+        let mut absolute = 0.0;
+        for &coeff in self.coefficients().iter().rev() {
+            absolute = absolute * x.abs() + coeff.abs();
+        }
+        absolute / self.eval(x).abs()
+    }
+
+    /// Returns the polynomial with every coefficient turned into a complex number.
+    pub fn to_complex(&self) -> Polynomial<Complex<f64>> {
+        Polynomial::from_vector(
+            self.coefficients()
+                .iter()
+                .map(|&c| Complex::new(c, 0.0))
+                .collect(),
+        )
+    }
+
+    /// Evaluates the polynomial at the point `i omega` of the imaginary axis,
+    /// as needed for the frequency response of a transfer function.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::Complex;
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let p = Polynomial::from_vector(vec![1.0, 1.0]); // 1 + s
+    /// assert_eq!(p.frequency_response(2.0), Complex::new(1.0, 2.0));
+    /// ```
+    pub fn frequency_response(&self, omega: f64) -> Complex<f64> {
+        self.eval(Complex::new(0.0, omega))
+    }
+
+    /// Evaluates the polynomial at every point of `xs`.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let p = Polynomial::from_vector(vec![1.0, 2.0, 3.0]); // 1 + 2x + 3x^2
+    /// assert_eq!(p.eval_slice(&[0.0, 1.0, 2.0]), vec![1.0, 6.0, 17.0]);
+    /// ```
+    pub fn eval_slice(&self, xs: &[f64]) -> Vec<f64> {
+        let mut values = vec![0.0; xs.len()];
+        self.eval_slice_into(xs, &mut values);
+        values
+    }
+
+    /// Evaluates the polynomial at every point of `xs`, writing the results into `values`.
+    /// Nothing is allocated, so this is suited for evaluating in a hot loop.
+    /// # Panics
+    /// Panics if `values` and `xs` have different lengths.
+    pub fn eval_slice_into(&self, xs: &[f64], values: &mut [f64]) {
+        assert_eq!(
+            xs.len(),
+            values.len(),
+            "output length must match input length"
+        );
+        let coefficients = self.coefficients();
+
+        let mut x_chunks = xs.chunks_exact(LANES);
+        let mut value_chunks = values.chunks_exact_mut(LANES);
+        for (x, value) in (&mut x_chunks).zip(&mut value_chunks) {
+            let mut acc = [0.0; LANES];
+            for &coeff in coefficients.iter().rev() {
+                for lane in 0..LANES {
+                    acc[lane] = acc[lane] * x[lane] + coeff;
+                }
+            }
+            value.copy_from_slice(&acc);
+        }
+
+        for (x, value) in x_chunks
+            .remainder()
+            .iter()
+            .zip(value_chunks.into_remainder())
+        {
+            let mut acc = 0.0;
+            for &coeff in coefficients.iter().rev() {
+                acc = acc * *x + coeff;
+            }
+            *value = acc;
+        }
+    }
+
+    /// Evaluates the polynomial and its derivative at every point of `xs`,
+    /// writing the results into `values` and `derivatives`.
+    /// # Panics
+    /// Panics if `values` or `derivatives` have a different length than `xs`.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let p = Polynomial::from_vector(vec![1.0, 2.0, 3.0]); // 1 + 2x + 3x^2
+    /// let mut values = [0.0; 2];
+    /// let mut derivatives = [0.0; 2];
+    /// p.eval_slice_with_derivative(&[1.0, 2.0], &mut values, &mut derivatives);
+    /// assert_eq!(values, [6.0, 17.0]);
+    /// assert_eq!(derivatives, [8.0, 14.0]);
+    /// ```
+    pub fn eval_slice_with_derivative(
+        &self,
+        xs: &[f64],
+        values: &mut [f64],
+        derivatives: &mut [f64],
+    ) {
+        assert_eq!(
+            xs.len(),
+            values.len(),
+            "output length must match input length"
+        );
+        assert_eq!(
+            xs.len(),
+            derivatives.len(),
+            "output length must match input length"
+        );
+        let coefficients = self.coefficients();
+
+        let mut x_chunks = xs.chunks_exact(LANES);
+        let mut value_chunks = values.chunks_exact_mut(LANES);
+        let mut derivative_chunks = derivatives.chunks_exact_mut(LANES);
+        for ((x, value), derivative) in (&mut x_chunks)
+            .zip(&mut value_chunks)
+            .zip(&mut derivative_chunks)
+        {
+            let mut acc = [0.0; LANES];
+            let mut derivative_acc = [0.0; LANES];
+            for &coeff in coefficients.iter().rev() {
+                for lane in 0..LANES {
+                    derivative_acc[lane] = derivative_acc[lane] * x[lane] + acc[lane];
+                    acc[lane] = acc[lane] * x[lane] + coeff;
+                }
+            }
+            value.copy_from_slice(&acc);
+            derivative.copy_from_slice(&derivative_acc);
+        }
+
+        for ((x, value), derivative) in x_chunks
+            .remainder()
+            .iter()
+            .zip(value_chunks.into_remainder())
+            .zip(derivative_chunks.into_remainder())
+        {
+            (*value, *derivative) = self.eval_with_derivative(*x);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Polynomial<f64> {
+        Polynomial::from_vector(vec![0.5, -1.0, 2.0, 0.0, -3.0, 1.0, 0.25])
+    }
+
+    #[test]
+    fn test_eval_estrin() {
+        let p = sample();
+        for x in [-2.0, -0.5, 0.0, 1.0, 3.0] {
+            assert!((p.eval_estrin(x) - p.eval(x)).abs() < 1e-9);
+        }
+        assert_eq!(Polynomial::from_constant(4.0).eval_estrin(2.0), 4.0);
+        let long = Polynomial::from_vector((0..13).map(|it| it as f64 - 6.0).collect());
+        for x in [-1.5, 0.25, 1.1] {
+            assert!((long.eval_estrin(x) - long.eval(x)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_eval_with_derivative() {
+        let p = sample();
+        let d = p.derivative();
+        for x in [-2.0, -0.5, 0.0, 1.0, 3.0] {
+            let (value, derivative) = p.eval_with_derivative(x);
+            assert!((value - p.eval(x)).abs() < 1e-9);
+            assert!((derivative - d.eval(x)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_eval_slice() {
+        let p = sample();
+        // more points than lanes, with a remainder
+        let xs: Vec<f64> = (0..19).map(|it| it as f64 / 4.0 - 2.0).collect();
+        let values = p.eval_slice(&xs);
+        for (x, value) in xs.iter().zip(values) {
+            assert!((value - p.eval(*x)).abs() < 1e-9);
+        }
+        assert!(p.eval_slice(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_eval_slice_with_derivative() {
+        let p = sample();
+        let d = p.derivative();
+        let xs: Vec<f64> = (0..21).map(|it| it as f64 / 8.0 - 1.0).collect();
+        let mut values = vec![0.0; xs.len()];
+        let mut derivatives = vec![0.0; xs.len()];
+        p.eval_slice_with_derivative(&xs, &mut values, &mut derivatives);
+        for (it, x) in xs.iter().enumerate() {
+            assert!((values[it] - p.eval(*x)).abs() < 1e-9);
+            assert!((derivatives[it] - d.eval(*x)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_frequency_response() {
+        let p = sample();
+        for omega in [0.0, 0.5, 2.0] {
+            let response = p.frequency_response(omega);
+            let expected = p.to_complex().eval(Complex::new(0.0, omega));
+            assert!((response - expected).norm() < 1e-12);
+        }
+        assert_eq!(p.frequency_response(0.0), Complex::new(0.5, 0.0));
+    }
+
+    #[test]
+    fn test_eval_compensated_ill_conditioned() {
+        // (x - 1)^8 expanded
+        let p = Polynomial::from_vector(vec![1.0, -8.0, 28.0, -56.0, 70.0, -56.0, 28.0, -8.0, 1.0]);
+        for x in [1.01, 0.99, 1.005] {
+            let exact = (x - 1.0f64).powi(8);
+            let (value, error_bound) = p.eval_compensated(x);
+            assert!((value - exact).abs() <= error_bound);
+            assert!((value - exact).abs() / exact < 1e-12);
+            // plain Horner does not get a single digit right here
+            assert!((p.eval(x) - exact).abs() / exact > 1e-1);
+            assert!(p.eval_condition_number(x) > 1e15);
+        }
+    }
+
+    #[test]
+    fn test_eval_compensated_well_conditioned() {
+        let p = sample();
+        for x in [-2.0, -0.5, 0.0, 1.0, 3.0] {
+            let (value, error_bound) = p.eval_compensated(x);
+            assert_eq!(value, p.eval(x));
+            assert!(error_bound <= 2.0 * f64::EPSILON * value.abs().max(1.0));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "output length must match input length")]
+    fn test_eval_slice_into_length_mismatch() {
+        sample().eval_slice_into(&[1.0, 2.0], &mut [0.0]);
+    }
+}
@@ -0,0 +1,289 @@
+#![forbid(unsafe_code)]
+//! # Interpolation
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use num::{One, Zero};
+
+use super::Polynomial;
+
+impl<T> Polynomial<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Returns the unique polynomial of minimal degree passing through the given `(x, y)` points.
+    /// The coefficients are computed with Newton divided differences, so `T` is expected to be a field.
+    /// # Panics
+    /// Panics if two points share the same `x`.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let p = Polynomial::interpolate(&[(0.0, 1.0), (1.0, 3.0), (2.0, 7.0)]);
+    /// assert_eq!(p, Polynomial::from_vector(vec![1.0, 1.0, 1.0])); // 1 + x + x^2
+    /// ```
+    pub fn interpolate(points: &[(T, T)]) -> Self {
+        let mut interpolator = NewtonInterpolator::new();
+        for (x, y) in points {
+            interpolator.add_point(x.clone(), y.clone());
+        }
+        interpolator.polynomial().clone()
+    }
+
+    /// Returns the unique polynomial of minimal degree matching the prescribed values and derivatives.
+    /// Every node comes with `[f(x), f'(x), ..., f^(k)(x)]`, the number of derivatives may differ between nodes.
+    /// The degree of the result is less than the total number of prescribed values.
+    /// # Panics
+    /// Panics if two nodes are equal.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// // p(0) = 1, p'(0) = 2, p(1) = 4
+    /// let p = Polynomial::interpolate_hermite(&[(0.0, vec![1.0, 2.0]), (1.0, vec![4.0])]);
+    /// assert_eq!(p, Polynomial::from_vector(vec![1.0, 2.0, 1.0])); // 1 + 2x + x^2
+    /// ```
+    pub fn interpolate_hermite(nodes: &[(T, Vec<T>)]) -> Self {
+        let mut interpolator = NewtonInterpolator::new();
+        for (x, values) in nodes {
+            interpolator.add_node_with_derivatives(x.clone(), values);
+        }
+        interpolator.polynomial().clone()
+    }
+}
+
+/// An incremental Newton interpolator.
+/// Points are added one at a time, each addition costing `O(n)` operations on the coefficients.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NewtonInterpolator<T>
+where
+    T: One + Zero + Clone,
+{
+    nodes: Vec<T>,
+    /// The last row of the divided differences table: `f[x_{n-j}, ..., x_n]` at index `j`.
+    differences: Vec<T>,
+    /// The Newton coefficients `f[x_0, ..., x_k]`.
+    newton_coefficients: Vec<T>,
+    /// The product `(x - x_0) * ... * (x - x_{n})`.
+    nodes_product: Polynomial<T>,
+    polynomial: Polynomial<T>,
+}
+
+impl<T> NewtonInterpolator<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Creates a new `NewtonInterpolator` without any points.
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            differences: Vec::new(),
+            newton_coefficients: Vec::new(),
+            nodes_product: Polynomial::from_constant(T::one()),
+            polynomial: Polynomial::zero(),
+        }
+    }
+
+    /// Adds the point `(x, y)` and updates the interpolating polynomial.
+    /// # Panics
+    /// Panics if a point with the same `x` has already been added.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::{NewtonInterpolator, Polynomial};
+    /// let mut interpolator = NewtonInterpolator::new();
+    /// interpolator.add_point(0.0, 1.0);
+    /// assert_eq!(interpolator.polynomial(), &Polynomial::from_vector(vec![1.0]));
+    /// interpolator.add_point(1.0, 3.0);
+    /// assert_eq!(interpolator.polynomial(), &Polynomial::from_vector(vec![1.0, 2.0]));
+    /// ```
+    pub fn add_point(&mut self, x: T, y: T) {
+        self.add_node_with_derivatives(x, &[y]);
+    }
+
+    /// Adds the node `x` with the prescribed values `[f(x), f'(x), ..., f^(k)(x)]`
+    /// and updates the interpolating polynomial.
+    /// The node is repeated `k + 1` times in the Newton basis, as in Hermite interpolation.
+    /// # Panics
+    /// Panics if the node `x` has already been added.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::{NewtonInterpolator, Polynomial};
+    /// let mut interpolator = NewtonInterpolator::new();
+    /// interpolator.add_node_with_derivatives(0.0, &[0.0, 0.0]);
+    /// interpolator.add_node_with_derivatives(1.0, &[1.0, 0.0]);
+    /// // 3x^2 - 2x^3
+    /// assert_eq!(interpolator.polynomial(), &Polynomial::from_vector(vec![0.0, 0.0, 3.0, -2.0]));
+    /// ```
+    pub fn add_node_with_derivatives(&mut self, x: T, values: &[T]) {
+        let mut factorials = vec![T::one()];
+        let mut order = T::one();
+        for j in 1..values.len() {
+            factorials.push(factorials[j - 1].clone() * order.clone());
+            order = order + T::one();
+        }
+
+        for copy in 0..values.len() {
+            let mut differences = Vec::with_capacity(self.differences.len() + 1);
+            differences.push(values[0].clone());
+            for (j, previous) in self.differences.iter().enumerate() {
+                if j < copy {
+                    // f[x, ..., x] with j + 2 copies of x is f^(j + 1)(x) / (j + 1)!
+                    differences.push(values[j + 1].clone() / factorials[j + 1].clone());
+                } else {
+                    let step = x.clone() - self.nodes[self.nodes.len() - 1 - j].clone();
+                    assert!(!step.is_zero(), "interpolation nodes must be distinct");
+                    differences.push((differences[j].clone() - previous.clone()) / step);
+                }
+            }
+            self.push_node(x.clone(), differences);
+        }
+    }
+
+    fn push_node(&mut self, x: T, differences: Vec<T>) {
+        let coefficient = differences[differences.len() - 1].clone();
+        self.polynomial =
+            self.polynomial.clone() + self.nodes_product.clone() * coefficient.clone();
+        self.nodes_product = self.nodes_product.clone() * (Polynomial::x() - x.clone());
+
+        self.nodes.push(x);
+        self.differences = differences;
+        self.newton_coefficients.push(coefficient);
+    }
+
+    /// Returns the nodes added so far.
+    /// A node added with derivatives is repeated once per prescribed value.
+    pub fn nodes(&self) -> &[T] {
+        &self.nodes
+    }
+
+    /// Returns the coefficients of the interpolating polynomial in the Newton basis
+    /// `1, (x - x_0), (x - x_0)(x - x_1), ...`.
+    pub fn newton_coefficients(&self) -> &[T] {
+        &self.newton_coefficients
+    }
+
+    /// Returns the polynomial of minimal degree passing through all the points added so far.
+    pub fn polynomial(&self) -> &Polynomial<T> {
+        &self.polynomial
+    }
+
+    /// Evaluates the interpolating polynomial at `x` using the Newton form.
+    pub fn eval(&self, x: T) -> T {
+        let mut result = T::zero();
+        for (coefficient, node) in self.newton_coefficients.iter().zip(&self.nodes).rev() {
+            result = result * (x.clone() - node.clone()) + coefficient.clone();
+        }
+        result
+    }
+}
+
+impl<T> Default for NewtonInterpolator<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coefficients::RationalNumber;
+
+    fn r(n: i64) -> RationalNumber {
+        RationalNumber::new(n, 1)
+    }
+
+    #[test]
+    fn test_interpolate_rational() {
+        // 2 - x + x^3 / 2
+        let expected = Polynomial::from_vector(vec![r(2), r(-1), r(0), RationalNumber::new(1, 2)]);
+        let points: Vec<_> = (-2..2).map(|x| (r(x), expected.eval(r(x)))).collect();
+        assert_eq!(Polynomial::interpolate(&points), expected);
+    }
+
+    #[test]
+    fn test_interpolate_minimal_degree() {
+        let points = [(r(0), r(3)), (r(1), r(3)), (r(5), r(3))];
+        assert_eq!(
+            Polynomial::interpolate(&points),
+            Polynomial::from_constant(r(3))
+        );
+    }
+
+    #[test]
+    fn test_interpolate_empty() {
+        assert!(Polynomial::<RationalNumber>::interpolate(&[]).is_zero());
+    }
+
+    #[test]
+    fn test_incremental_interpolator() {
+        let mut interpolator = NewtonInterpolator::new();
+        interpolator.add_point(r(1), r(1));
+        interpolator.add_point(r(2), r(4));
+        assert_eq!(interpolator.newton_coefficients(), &[r(1), r(3)]);
+        interpolator.add_point(r(3), r(9));
+        assert_eq!(interpolator.newton_coefficients(), &[r(1), r(3), r(1)]);
+        assert_eq!(
+            interpolator.polynomial(),
+            &Polynomial::from_vector(vec![r(0), r(0), r(1)])
+        );
+        assert_eq!(interpolator.eval(r(7)), r(49));
+        assert_eq!(interpolator.nodes(), &[r(1), r(2), r(3)]);
+    }
+
+    #[test]
+    fn test_interpolate_hermite_smoothstep() {
+        // position, velocity and acceleration fixed at both ends
+        let p = Polynomial::interpolate_hermite(&[
+            (r(0), vec![r(0), r(0), r(0)]),
+            (r(1), vec![r(1), r(0), r(0)]),
+        ]);
+        assert_eq!(
+            p,
+            Polynomial::from_vector(vec![r(0), r(0), r(0), r(10), r(-15), r(6)])
+        );
+    }
+
+    #[test]
+    fn test_interpolate_hermite_matches_derivatives() {
+        let nodes = [
+            (r(-1), vec![r(2), r(-3), r(4), r(6)]),
+            (r(0), vec![r(1)]),
+            (r(2), vec![r(5), RationalNumber::new(1, 2)]),
+        ];
+        let p = Polynomial::interpolate_hermite(&nodes);
+        assert_eq!(p.degree(), 6);
+        for (x, values) in nodes {
+            let mut derivative = p.clone();
+            for value in values {
+                assert_eq!(derivative.eval(x), value);
+                derivative = derivative.derivative();
+            }
+        }
+    }
+
+    #[test]
+    fn test_interpolate_hermite_f64() {
+        let p = Polynomial::<f64>::interpolate_hermite(&[
+            (0.0, vec![1.0, 0.0, -1.0]),
+            (2.0, vec![0.0]),
+        ]);
+        let d = p.derivative();
+        assert!((p.eval(0.0) - 1.0).abs() < 1e-12);
+        assert!(d.eval(0.0).abs() < 1e-12);
+        assert!((d.derivative().eval(0.0) + 1.0).abs() < 1e-12);
+        assert!(p.eval(2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "interpolation nodes must be distinct")]
+    fn test_interpolate_hermite_repeated_nodes() {
+        Polynomial::interpolate_hermite(&[(r(1), vec![r(1), r(0)]), (r(1), vec![r(2)])]);
+    }
+
+    #[test]
+    #[should_panic(expected = "interpolation nodes must be distinct")]
+    fn test_interpolate_repeated_nodes() {
+        Polynomial::interpolate(&[(r(1), r(1)), (r(1), r(2))]);
+    }
+}
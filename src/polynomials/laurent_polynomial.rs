@@ -0,0 +1,361 @@
+#![forbid(unsafe_code)]
+//! # Laurent polynomials
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use num::{One, Zero};
+
+use super::Polynomial;
+
+/// A Laurent polynomial `x^v (a_0 + a_1 x + ... + a_n x^n)`, whose exponents may be negative.
+/// The valuation `v` is the lowest exponent; it is kept so that `a_0` is non-zero,
+/// except for the zero Laurent polynomial, whose valuation is `0`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LaurentPolynomial<T>
+where
+    T: One + Zero + Clone,
+{
+    polynomial: Polynomial<T>,
+    valuation: i64,
+}
+
+impl<T> LaurentPolynomial<T>
+where
+    T: Clone + Zero + One,
+{
+    /// Create a new `LaurentPolynomial` `x^valuation p(x)`.
+    /// The low-order zero coefficients of `p` are moved into the valuation.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::{LaurentPolynomial, Polynomial};
+    /// let p = LaurentPolynomial::new(Polynomial::from_vector(vec![0, 2, 3]), -3); // 2x^-2 + 3x^-1
+    /// assert_eq!(p.valuation(), -2);
+    /// assert_eq!(p.degree(), -1);
+    /// assert_eq!(p.coefficient(-1), 3);
+    /// ```
+    pub fn new(polynomial: Polynomial<T>, valuation: i64) -> Self {
+        let coefficients = polynomial.coefficients();
+        match coefficients.iter().position(|c| !c.is_zero()) {
+            None => Self::zero(),
+            Some(0) => Self {
+                polynomial,
+                valuation,
+            },
+            Some(shift) => Self {
+                polynomial: Polynomial::from_vector(coefficients[shift..].to_vec()),
+                valuation: valuation + shift as i64,
+            },
+        }
+    }
+
+    /// Create a new `LaurentPolynomial` representing `x^n`, where `n` may be negative.
+    pub fn x_pow(n: i64) -> Self {
+        Self {
+            polynomial: Polynomial::from_constant(T::one()),
+            valuation: n,
+        }
+    }
+
+    /// Returns the lowest exponent, or `0` for the zero Laurent polynomial.
+    pub fn valuation(&self) -> i64 {
+        self.valuation
+    }
+
+    /// Returns the highest exponent, or `0` for the zero Laurent polynomial.
+    pub fn degree(&self) -> i64 {
+        self.valuation + self.polynomial.degree() as i64
+    }
+
+    /// Returns the coefficients from the exponent [`LaurentPolynomial::valuation`] up to [`LaurentPolynomial::degree`].
+    pub fn coefficients(&self) -> &[T] {
+        self.polynomial.coefficients()
+    }
+
+    /// Returns the coefficient of `x^n`.
+    pub fn coefficient(&self, n: i64) -> T {
+        usize::try_from(n - self.valuation)
+            .ok()
+            .and_then(|it| self.polynomial.coefficients().get(it))
+            .cloned()
+            .unwrap_or_else(T::zero)
+    }
+
+    /// Returns the polynomial `p` such that the Laurent polynomial is `x^v p(x)` with the valuation `v`.
+    pub fn polynomial(&self) -> &Polynomial<T> {
+        &self.polynomial
+    }
+
+    /// Returns the Laurent polynomial multiplied by `x^n`.
+    pub fn shift(&self, n: i64) -> Self {
+        if self.is_zero() {
+            return self.clone();
+        }
+        Self {
+            polynomial: self.polynomial.clone(),
+            valuation: self.valuation + n,
+        }
+    }
+
+    /// Returns the Laurent polynomial with `x` replaced by `x^-1`.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::{LaurentPolynomial, Polynomial};
+    /// let p = LaurentPolynomial::from(Polynomial::from_vector(vec![1, 2, 3])); // 1 + 2x + 3x^2
+    /// let q = LaurentPolynomial::new(Polynomial::from_vector(vec![3, 2, 1]), -2); // 3x^-2 + 2x^-1 + 1
+    /// assert_eq!(p.reflect(), q);
+    /// ```
+    pub fn reflect(&self) -> Self {
+        let mut coefficients = self.polynomial.coefficients().to_vec();
+        coefficients.reverse();
+        Self::new(Polynomial::from_vector(coefficients), -self.degree())
+    }
+
+    /// Returns the coefficients starting from the exponent `valuation`,
+    /// which must not exceed the valuation of `self`.
+    fn coefficients_from(&self, valuation: i64) -> Vec<T> {
+        let shift = (self.valuation - valuation) as usize;
+        let mut coefficients = vec![T::zero(); shift];
+        coefficients.extend_from_slice(self.polynomial.coefficients());
+        coefficients
+    }
+
+    /// Evaluate the Laurent polynomial at an invertible value.
+    /// The polynomial part is evaluated with [`Polynomial::eval`] and then multiplied by `x^v`,
+    /// which is computed with `O(log |v|)` multiplications.
+    /// # Panics
+    /// Panics if `x` is zero and the valuation is negative.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::{LaurentPolynomial, Polynomial};
+    /// let p = LaurentPolynomial::new(Polynomial::from_vector(vec![1.0, 0.0, 1.0]), -1); // x^-1 + x
+    /// assert_eq!(p.eval(2.0), 2.5);
+    /// ```
+    pub fn eval<U>(&self, x: U) -> U
+    where
+        U: Clone
+            + Zero
+            + One
+            + Add<Output = U>
+            + Mul<Output = U>
+            + Div<Output = U>
+            + Mul<T, Output = U>,
+    {
+        let value = self.polynomial.eval(x.clone());
+        // x^|v| by repeated squaring, as the valuation can be large
+        let mut power = U::one();
+        let mut square = x.clone();
+        let mut exponent = self.valuation.unsigned_abs();
+        while exponent > 0 {
+            if exponent % 2 == 1 {
+                power = power * square.clone();
+            }
+            exponent /= 2;
+            if exponent > 0 {
+                square = square.clone() * square;
+            }
+        }
+        if self.valuation >= 0 {
+            value * power
+        } else {
+            assert!(!x.is_zero(), "evaluation at zero with negative exponents");
+            value / power
+        }
+    }
+}
+
+impl<T> From<Polynomial<T>> for LaurentPolynomial<T>
+where
+    T: Clone + Zero + One,
+{
+    fn from(polynomial: Polynomial<T>) -> Self {
+        Self::new(polynomial, 0)
+    }
+}
+
+impl<T> TryFrom<LaurentPolynomial<T>> for Polynomial<T>
+where
+    T: Clone + Zero + One,
+{
+    type Error = LaurentPolynomial<T>;
+
+    /// Converts a Laurent polynomial without negative exponents.
+    /// The Laurent polynomial is given back otherwise.
+    fn try_from(laurent: LaurentPolynomial<T>) -> Result<Self, Self::Error> {
+        if laurent.valuation < 0 {
+            return Err(laurent);
+        }
+        Ok(Polynomial::from_vector(laurent.coefficients_from(0)))
+    }
+}
+
+impl<T> Add for LaurentPolynomial<T>
+where
+    T: Clone + Zero + One + Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let valuation = self.valuation.min(rhs.valuation);
+        let sum = Polynomial::from_vector(self.coefficients_from(valuation))
+            + Polynomial::from_vector(rhs.coefficients_from(valuation));
+        Self::new(sum, valuation)
+    }
+}
+
+impl<T> Sub for LaurentPolynomial<T>
+where
+    T: Clone + Zero + One + Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let valuation = self.valuation.min(rhs.valuation);
+        let difference = Polynomial::from_vector(self.coefficients_from(valuation))
+            - Polynomial::from_vector(rhs.coefficients_from(valuation));
+        Self::new(difference, valuation)
+    }
+}
+
+impl<T> Neg for LaurentPolynomial<T>
+where
+    T: Clone + Zero + One + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            polynomial: -self.polynomial,
+            valuation: self.valuation,
+        }
+    }
+}
+
+impl<T> Mul for LaurentPolynomial<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.polynomial * rhs.polynomial,
+            self.valuation + rhs.valuation,
+        )
+    }
+}
+
+impl<T> Mul<T> for LaurentPolynomial<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.polynomial * rhs, self.valuation)
+    }
+}
+
+impl<T> Zero for LaurentPolynomial<T>
+where
+    T: Clone + Zero + One,
+{
+    fn zero() -> Self {
+        Self {
+            polynomial: Polynomial::zero(),
+            valuation: 0,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.polynomial.is_zero()
+    }
+}
+
+impl<T> One for LaurentPolynomial<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    fn one() -> Self {
+        Self::x_pow(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn laurent(coefficients: Vec<i64>, valuation: i64) -> LaurentPolynomial<i64> {
+        LaurentPolynomial::new(Polynomial::from_vector(coefficients), valuation)
+    }
+
+    #[test]
+    fn test_new_normalizes() {
+        let p = laurent(vec![0, 0, 1, 2], -4);
+        assert_eq!(p.valuation(), -2);
+        assert_eq!(p.degree(), -1);
+        assert_eq!(p.coefficients(), &[1, 2]);
+        assert_eq!(p.coefficient(-2), 1);
+        assert_eq!(p.coefficient(-3), 0);
+        assert_eq!(p.coefficient(5), 0);
+        assert_eq!(laurent(vec![0], -3), LaurentPolynomial::zero());
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let a = laurent(vec![1, 2], -2); // x^-2 + 2x^-1
+        let b = laurent(vec![3, 0, 1], 0); // 3 + x^2
+        assert_eq!(a.clone() + b.clone(), laurent(vec![1, 2, 3, 0, 1], -2));
+        assert_eq!(b.clone() - a.clone(), laurent(vec![-1, -2, 3, 0, 1], -2));
+        assert!((a.clone() - a).is_zero());
+        // the cancellation of the lowest terms raises the valuation
+        let c = laurent(vec![1, 5], -1);
+        let d = laurent(vec![1, 3], -1);
+        assert_eq!((c - d).valuation(), 0);
+        assert_eq!(-b, laurent(vec![-3, 0, -1], 0));
+    }
+
+    #[test]
+    fn test_mul() {
+        let a = laurent(vec![1, 1], -1); // x^-1 + 1
+        let b = laurent(vec![1, -1], 0); // 1 - x
+        assert_eq!(a.clone() * b, laurent(vec![1, 0, -1], -1)); // x^-1 - x
+        assert_eq!(
+            LaurentPolynomial::<i64>::x_pow(-3) * LaurentPolynomial::x_pow(3),
+            LaurentPolynomial::one()
+        );
+        assert_eq!(a * 2, laurent(vec![2, 2], -1));
+    }
+
+    #[test]
+    fn test_eval() {
+        let p = LaurentPolynomial::new(Polynomial::from_vector(vec![2.0, 0.0, 1.0]), -2); // 2x^-2 + 1
+        assert_eq!(p.eval(2.0), 1.5);
+        assert_eq!(p.shift(3).eval(2.0), 12.0);
+        assert_eq!(p.reflect().eval(0.5), p.eval(2.0));
+    }
+
+    #[test]
+    fn test_eval_large_valuation() {
+        let p = LaurentPolynomial::<f64>::x_pow(-(1 << 40));
+        assert_eq!(p.eval(2.0), 0.0);
+        assert_eq!(p.eval(-1.0), 1.0);
+        assert_eq!(LaurentPolynomial::<i64>::x_pow(61).eval(2), 1 << 61);
+        assert_eq!(LaurentPolynomial::<f64>::x_pow(-5).eval(2.0), 1.0 / 32.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "evaluation at zero with negative exponents")]
+    fn test_eval_at_zero() {
+        LaurentPolynomial::new(Polynomial::from_constant(1.0), -1).eval(0.0);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let p = Polynomial::from_vector(vec![0, 1, 2]);
+        let laurent = LaurentPolynomial::from(p.clone());
+        assert_eq!(laurent.valuation(), 1);
+        assert_eq!(Polynomial::try_from(laurent.clone()), Ok(p));
+        let shifted = laurent.shift(-2);
+        assert_eq!(Polynomial::try_from(shifted.clone()), Err(shifted));
+    }
+}
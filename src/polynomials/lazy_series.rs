@@ -0,0 +1,559 @@
+#![forbid(unsafe_code)]
+//! # Lazy power series
+//!
+//! Infinite formal power series whose coefficients are computed on demand and memoized.
+//!
+//! Every series is a generator `k -> a_k` together with the coefficients it has produced so far.
+//! The arithmetic builds new generators out of the operands, so an expression only costs
+//! what is needed for the coefficients actually requested.
+//! Recursive definitions such as `C = 1 + x C^2` are tied with [`LazySeries::fixed_point`].
+
+use std::cell::RefCell;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::rc::{Rc, Weak};
+
+use num::{One, Zero};
+
+use super::{Polynomial, PowerSeries};
+
+type Generator<T> = Rc<dyn Fn(usize) -> T>;
+
+struct LazyState<T> {
+    coefficients: Vec<T>,
+    generator: Generator<T>,
+    computing: bool,
+}
+
+/// An infinite formal power series with lazily computed, memoized coefficients.
+/// Cloning a `LazySeries` is cheap and the clones share their coefficients.
+pub struct LazySeries<T> {
+    state: Rc<RefCell<LazyState<T>>>,
+}
+
+impl<T> Clone for LazySeries<T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: Rc::clone(&self.state),
+        }
+    }
+}
+
+/// Shows the coefficients computed so far.
+impl<T> fmt::Debug for LazySeries<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazySeries")
+            .field("coefficients", &self.state.borrow().coefficients)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> LazySeries<T>
+where
+    T: Clone + 'static,
+{
+    /// Create a new `LazySeries` whose coefficient of `x^k` is `f(k)`.
+    /// The coefficients are requested in increasing order, and each of them only once.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::LazySeries;
+    /// let s = LazySeries::from_fn(|k| k as i64); // x + 2x^2 + 3x^3 + ...
+    /// assert_eq!(s.coefficients(4), vec![0, 1, 2, 3]);
+    /// ```
+    pub fn from_fn(f: impl Fn(usize) -> T + 'static) -> Self {
+        Self {
+            state: Rc::new(RefCell::new(LazyState {
+                coefficients: Vec::new(),
+                generator: Rc::new(f),
+                computing: false,
+            })),
+        }
+    }
+
+    /// Returns the coefficient of `x^k`, computing the missing coefficients up to `k`.
+    /// # Panics
+    /// Panics if the coefficient depends on itself, e.g. for a badly founded [`LazySeries::fixed_point`].
+    pub fn coefficient(&self, k: usize) -> T {
+        loop {
+            let (next, generator) = {
+                let state = self.state.borrow();
+                if let Some(coefficient) = state.coefficients.get(k) {
+                    return coefficient.clone();
+                }
+                assert!(!state.computing, "the coefficient depends on itself");
+                (state.coefficients.len(), Rc::clone(&state.generator))
+            };
+            self.state.borrow_mut().computing = true;
+            let coefficient = generator(next);
+            let mut state = self.state.borrow_mut();
+            state.computing = false;
+            state.coefficients.push(coefficient);
+        }
+    }
+
+    /// Returns the coefficients of `x^0, ..., x^(n-1)`.
+    pub fn coefficients(&self, n: usize) -> Vec<T> {
+        (0..n).map(|k| self.coefficient(k)).collect()
+    }
+
+    /// Returns the number of coefficients computed so far.
+    pub fn computed(&self) -> usize {
+        self.state.borrow().coefficients.len()
+    }
+
+    /// Returns the series defined by the equation `s = f(s)`.
+    /// `f` must only build the expression: reading a coefficient of `s` before `f` returns panics.
+    /// The coefficient of `x^k` of `f(s)` must only depend on the coefficients of `s` below `k`,
+    /// e.g. because `s` is multiplied by `x`.
+    /// Products only request a coefficient of their right operand when the matching coefficient
+    /// of the left operand is non-zero, so `x * s * s` is well founded, whereas `s * s * x` is not.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::LazySeries;
+    /// // the Catalan numbers: C = 1 + x C^2
+    /// let catalan = LazySeries::fixed_point(|c| LazySeries::<i64>::x() * c.clone() * c + 1);
+    /// assert_eq!(catalan.coefficients(6), vec![1, 1, 2, 5, 14, 42]);
+    /// ```
+    pub fn fixed_point(f: impl FnOnce(Self) -> Self) -> Self {
+        let result =
+            Self::from_fn(|_| panic!("the series is used before its definition is complete"));
+        // the argument only refers to the result weakly, so that the series do not form a cycle
+        let weak: Weak<RefCell<LazyState<T>>> = Rc::downgrade(&result.state);
+        let argument = Self::from_fn(move |k| {
+            let state = weak.upgrade().expect("the fixed point is still alive");
+            Self { state }.coefficient(k)
+        });
+        let expression = f(argument);
+        result.state.borrow_mut().generator = Rc::new(move |k| expression.coefficient(k));
+        result
+    }
+}
+
+impl<T> LazySeries<T>
+where
+    T: Clone + Zero + One + 'static,
+{
+    /// Create a new `LazySeries` from a polynomial, with zero coefficients beyond its degree.
+    pub fn from_polynomial(p: &Polynomial<T>) -> Self {
+        let coefficients = p.coefficients().to_vec();
+        Self::from_fn(move |k| coefficients.get(k).cloned().unwrap_or_else(T::zero))
+    }
+
+    /// Create a new constant `LazySeries`.
+    pub fn constant(c: T) -> Self {
+        Self::from_polynomial(&Polynomial::from_constant(c))
+    }
+
+    /// Create a new `LazySeries` representing the `x` variable.
+    pub fn x() -> Self {
+        Self::from_polynomial(&Polynomial::x())
+    }
+
+    /// Returns the truncation of the series to the precision `O(x^n)`.
+    pub fn to_power_series(&self, n: usize) -> PowerSeries<T> {
+        let coefficients = self.coefficients(n);
+        if coefficients.is_empty() {
+            return PowerSeries::new(Polynomial::zero(), 0);
+        }
+        PowerSeries::new(Polynomial::from_vector(coefficients), n)
+    }
+
+    /// Returns the series multiplied by `x^k`.
+    /// The coefficients of the series are not requested before they are needed.
+    pub fn shift(&self, k: usize) -> Self {
+        let s = self.clone();
+        Self::from_fn(move |it| {
+            if it < k {
+                T::zero()
+            } else {
+                s.coefficient(it - k)
+            }
+        })
+    }
+}
+
+impl<T> LazySeries<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + 'static,
+{
+    /// Returns the formal derivative of the series.
+    pub fn derivative(&self) -> Self {
+        let s = self.clone();
+        Self::from_fn(move |k| {
+            let mut factor = T::zero();
+            for _ in 0..=k {
+                factor = factor + T::one();
+            }
+            s.coefficient(k + 1) * factor
+        })
+    }
+
+    /// Returns the composition `f(g(x))` of the series `f` with a series `g` without constant term.
+    /// The coefficient of `x^k` is `sum_j f_j [x^k] g^j`, and the powers of `g` are memoized as well.
+    /// # Panics
+    /// Panics, when the first coefficient is requested, if the constant term of `g` is not zero.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::LazySeries;
+    /// let geometric = LazySeries::from_fn(|_| 1); // 1 / (1 - x)
+    /// let double = LazySeries::x() * 2; // 2x
+    /// assert_eq!(geometric.compose(&double).coefficients(5), vec![1, 2, 4, 8, 16]);
+    /// ```
+    pub fn compose(&self, g: &Self) -> Self {
+        let f = self.clone();
+        let g = g.clone();
+        let powers = RefCell::new(vec![Self::constant(T::one())]);
+        Self::from_fn(move |k| {
+            if k == 0 {
+                assert!(
+                    g.coefficient(0).is_zero(),
+                    "the inner series must have no constant term"
+                );
+            }
+            // g^j has no terms below x^j, so only the powers up to g^k contribute
+            while powers.borrow().len() <= k {
+                let last = powers
+                    .borrow()
+                    .last()
+                    .cloned()
+                    .expect("g^0 is always present");
+                powers.borrow_mut().push(g.clone() * last);
+            }
+            let powers = powers.borrow();
+            let mut sum = T::zero();
+            for (j, power) in powers.iter().enumerate().take(k + 1) {
+                let coefficient = f.coefficient(j);
+                if !coefficient.is_zero() {
+                    sum = sum + coefficient * power.coefficient(k);
+                }
+            }
+            sum
+        })
+    }
+}
+
+impl<T> LazySeries<T>
+where
+    T: Clone
+        + Zero
+        + One
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + 'static,
+{
+    /// Returns the formal integral of the series with zero constant term.
+    pub fn integral(&self) -> Self {
+        let s = self.clone();
+        Self::from_fn(move |k| {
+            if k == 0 {
+                return T::zero();
+            }
+            let mut factor = T::zero();
+            for _ in 0..k {
+                factor = factor + T::one();
+            }
+            s.coefficient(k - 1) / factor
+        })
+    }
+
+    /// Returns the multiplicative inverse `g` of the series `f`, defined by `g = (1 - (f - f_0) g) / f_0`.
+    /// # Panics
+    /// Panics, when the first coefficient is requested, if the constant term is zero.
+    pub fn inverse(&self) -> Self {
+        let f = self.clone();
+        let tail = Self::from_fn(move |k| if k == 0 { T::zero() } else { f.coefficient(k) });
+        let f = self.clone();
+        let constant = Self::from_fn(move |k| {
+            let c = f.coefficient(0);
+            assert!(!c.is_zero(), "the constant term must be invertible");
+            if k == 0 {
+                T::one() / c
+            } else {
+                T::zero()
+            }
+        });
+        // the constant is multiplied as a series, so that its coefficient is only read on demand
+        Self::fixed_point(|g| constant.clone() - tail * g * constant)
+    }
+
+    /// Returns the exponential `h` of the series `g` with constant term zero, defined by `h = 1 + integral(g' h)`.
+    /// # Panics
+    /// Panics, when the first coefficient is requested, if the constant term is not zero.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::RationalNumber;
+    /// use rust_polynomials_lib::polynomials::LazySeries;
+    /// let x = LazySeries::<RationalNumber>::x();
+    /// let exp = x.exp();
+    /// assert_eq!(exp.coefficient(4), RationalNumber::new(1, 24));
+    /// ```
+    pub fn exp(&self) -> Self {
+        let g = self.clone();
+        Self::fixed_point(|h| {
+            let derivative = g.derivative();
+            let g = g.clone();
+            let integral = (derivative * h).integral();
+            Self::from_fn(move |k| {
+                if k == 0 {
+                    assert!(g.coefficient(0).is_zero(), "the constant term must be zero");
+                    return T::one();
+                }
+                integral.coefficient(k)
+            })
+        })
+    }
+
+    /// Returns the logarithm of the series with constant term one, as the integral of `f' / f`.
+    /// # Panics
+    /// Panics, when the first coefficient is requested, if the constant term is not one.
+    pub fn log(&self) -> Self {
+        let f = self.clone();
+        let integral = (self.derivative() / self.clone()).integral();
+        Self::from_fn(move |k| {
+            if k == 0 {
+                assert!(
+                    (f.coefficient(0) - T::one()).is_zero(),
+                    "the constant term must be one"
+                );
+            }
+            integral.coefficient(k)
+        })
+    }
+
+    /// Returns the square root of the series with constant term one, as `exp(log(f) / 2)`.
+    /// # Panics
+    /// Panics, when the first coefficient is requested, if the constant term is not one.
+    pub fn sqrt(&self) -> Self {
+        let half = T::one() / (T::one() + T::one());
+        (self.log() * half).exp()
+    }
+}
+
+impl<T> Add for LazySeries<T>
+where
+    T: Clone + Add<Output = T> + 'static,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::from_fn(move |k| self.coefficient(k) + rhs.coefficient(k))
+    }
+}
+
+impl<T> Add<T> for LazySeries<T>
+where
+    T: Clone + Add<Output = T> + 'static,
+{
+    type Output = Self;
+
+    /// adding rhs constant to the constant term of the series
+    fn add(self, rhs: T) -> Self {
+        Self::from_fn(move |k| {
+            if k == 0 {
+                self.coefficient(k) + rhs.clone()
+            } else {
+                self.coefficient(k)
+            }
+        })
+    }
+}
+
+impl<T> Sub for LazySeries<T>
+where
+    T: Clone + Sub<Output = T> + 'static,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_fn(move |k| self.coefficient(k) - rhs.coefficient(k))
+    }
+}
+
+impl<T> Neg for LazySeries<T>
+where
+    T: Clone + Neg<Output = T> + 'static,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_fn(move |k| -self.coefficient(k))
+    }
+}
+
+/// The Cauchy product.
+/// A coefficient of the right operand is only requested if the matching coefficient of the left operand is non-zero.
+impl<T> Mul for LazySeries<T>
+where
+    T: Clone + Zero + Add<Output = T> + Mul<Output = T> + 'static,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_fn(move |k| {
+            let mut sum = T::zero();
+            for i in 0..=k {
+                let a = self.coefficient(i);
+                if !a.is_zero() {
+                    sum = sum + a * rhs.coefficient(k - i);
+                }
+            }
+            sum
+        })
+    }
+}
+
+impl<T> Mul<T> for LazySeries<T>
+where
+    T: Clone + Mul<Output = T> + 'static,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Self::from_fn(move |k| self.coefficient(k) * rhs.clone())
+    }
+}
+
+/// Multiplication by the inverse, see [`LazySeries::inverse`].
+impl<T> Div for LazySeries<T>
+where
+    T: Clone
+        + Zero
+        + One
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + 'static,
+{
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inverse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coefficients::RationalNumber;
+
+    fn r(n: i64, d: i64) -> RationalNumber {
+        RationalNumber::new(n, d)
+    }
+
+    #[test]
+    fn test_memoization() {
+        let calls = Rc::new(RefCell::new(0));
+        let counter = Rc::clone(&calls);
+        let s = LazySeries::from_fn(move |k| {
+            *counter.borrow_mut() += 1;
+            k as i64
+        });
+        assert_eq!(s.computed(), 0);
+        assert_eq!(s.coefficient(5), 5);
+        assert_eq!(s.computed(), 6);
+        assert_eq!(s.clone().coefficient(3), 3);
+        assert_eq!(*calls.borrow(), 6);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = LazySeries::from_fn(|k| k as i64);
+        let b = LazySeries::from_fn(|_| 1);
+        assert_eq!((a.clone() + b.clone()).coefficients(4), vec![1, 2, 3, 4]);
+        assert_eq!((a.clone() - b.clone()).coefficients(4), vec![-1, 0, 1, 2]);
+        assert_eq!((-a.clone()).coefficients(3), vec![0, -1, -2]);
+        // (1 / (1 - x))^2 = 1 + 2x + 3x^2 + ...
+        assert_eq!((b.clone() * b.clone()).coefficients(4), vec![1, 2, 3, 4]);
+        assert_eq!((a * 3).coefficients(3), vec![0, 3, 6]);
+        assert_eq!(b.shift(2).coefficients(4), vec![0, 0, 1, 1]);
+        assert_eq!(b.derivative().coefficients(3), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        // the Fibonacci numbers: F = x + x F + x^2 F
+        let x = LazySeries::<i64>::x();
+        let fibonacci =
+            LazySeries::fixed_point(|f| x.clone() + x.clone() * f.clone() + x.shift(1) * f);
+        assert_eq!(fibonacci.coefficients(8), vec![0, 1, 1, 2, 3, 5, 8, 13]);
+        assert_eq!(fibonacci.coefficient(50), 12_586_269_025i64);
+    }
+
+    #[test]
+    #[should_panic(expected = "the coefficient depends on itself")]
+    fn test_ill_founded_fixed_point() {
+        let s = LazySeries::fixed_point(|s| s + 1);
+        s.coefficient(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "the series is used before its definition is complete")]
+    fn test_fixed_point_read_during_definition() {
+        LazySeries::fixed_point(|s: LazySeries<i64>| {
+            s.coefficient(0);
+            s
+        });
+    }
+
+    #[test]
+    fn test_inverse_inside_fixed_point() {
+        // s = x / s + 1, so s^2 = s + x
+        let s =
+            LazySeries::fixed_point(|s| LazySeries::<RationalNumber>::x() * s.inverse() + r(1, 1));
+        let integers = |v: &[i64]| v.iter().map(|&n| r(n, 1)).collect::<Vec<_>>();
+        assert_eq!(s.coefficients(6), integers(&[1, 1, -1, 2, -5, 14]));
+        let square = s.clone() * s.clone() - s - LazySeries::x();
+        assert!(square.coefficients(8).iter().all(|it| it.is_zero()));
+    }
+
+    #[test]
+    fn test_inverse_and_division() {
+        let f = LazySeries::from_polynomial(&Polynomial::from_vector(vec![r(2, 1), r(-1, 1)]));
+        let g = f.inverse();
+        assert_eq!(g.coefficients(4), vec![r(1, 2), r(1, 4), r(1, 8), r(1, 16)]);
+        let one = f.clone() / f;
+        assert_eq!(
+            one.coefficients(5),
+            vec![r(1, 1), r(0, 1), r(0, 1), r(0, 1), r(0, 1)]
+        );
+    }
+
+    #[test]
+    fn test_exp_log_sqrt() {
+        let s = LazySeries::from_polynomial(&Polynomial::from_vector(vec![
+            r(0, 1),
+            r(1, 1),
+            r(-3, 1),
+            r(2, 1),
+        ]));
+        assert_eq!(s.exp().log().coefficients(8), s.coefficients(8));
+        let t = s.clone() + r(1, 1);
+        let root = t.sqrt();
+        assert_eq!((root.clone() * root).coefficients(8), t.coefficients(8));
+        // matches the truncated series
+        assert_eq!(s.exp().to_power_series(8), s.to_power_series(8).exp());
+    }
+
+    #[test]
+    fn test_compose() {
+        // exp(x) composed with x + x^2
+        let x = LazySeries::<RationalNumber>::x();
+        let g = x.clone() + x.clone() * x.clone();
+        let composed = x.exp().compose(&g);
+        assert_eq!(composed.coefficients(6), g.exp().coefficients(6));
+    }
+
+    #[test]
+    #[should_panic(expected = "the inner series must have no constant term")]
+    fn test_compose_with_constant_term() {
+        let s = LazySeries::from_fn(|_| 1);
+        s.compose(&s).coefficient(0);
+    }
+}
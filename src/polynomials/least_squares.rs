@@ -0,0 +1,331 @@
+#![forbid(unsafe_code)]
+//! # Least squares fitting
+//!
+//! Weighted least-squares polynomial fits, computed with the polynomials orthogonal on the data points
+//! (Forsythe's method) rather than with the normal equations.
+//! Over an exact field such as `RationalNumber` the fit is exact.
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use num::{One, Zero};
+
+use super::Polynomial;
+
+/// The maximal number of sweeps of the one-sided Jacobi method computing the singular values.
+const JACOBI_SWEEPS: usize = 60;
+
+/// A least-squares polynomial fit of data points `(x_i, y_i)` with weights `w_i`,
+/// minimizing `sum w_i (y_i - p(x_i))^2`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeastSquaresFit<T>
+where
+    T: One + Zero + Clone,
+{
+    polynomial: Polynomial<T>,
+    xs: Vec<T>,
+    weights: Vec<T>,
+    degree: usize,
+    residuals: Vec<T>,
+}
+
+impl<T> LeastSquaresFit<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Returns the fitted polynomial.
+    pub fn polynomial(&self) -> &Polynomial<T> {
+        &self.polynomial
+    }
+
+    /// Returns the residuals `y_i - p(x_i)`.
+    pub fn residuals(&self) -> &[T] {
+        &self.residuals
+    }
+
+    /// Returns the weighted sum of squared residuals `sum w_i (y_i - p(x_i))^2`, the minimized quantity.
+    pub fn residual_sum_of_squares(&self) -> T {
+        self.residuals
+            .iter()
+            .zip(&self.weights)
+            .fold(T::zero(), |sum, (r, w)| {
+                sum + w.clone() * r.clone() * r.clone()
+            })
+    }
+}
+
+impl LeastSquaresFit<f64> {
+    /// Returns the 2-norm condition number of the weighted Vandermonde matrix `(sqrt(w_i) x_i^j)`,
+    /// i.e. the sensitivity of the coefficients of the fitted polynomial, in the monomial basis, to the data.
+    /// It is the ratio of the largest to the smallest singular value, computed with the one-sided Jacobi method,
+    /// and is infinite if the matrix is rank deficient.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let fit = Polynomial::fit(&[0.0, 1.0], &[1.0, 2.0], 1, None);
+    /// // the singular values of [[1, 0], [1, 1]] are the golden ratio and its inverse
+    /// let golden_ratio = (1.0 + 5f64.sqrt()) / 2.0;
+    /// assert!((fit.condition_number() - golden_ratio * golden_ratio).abs() < 1e-12);
+    /// ```
+    pub fn condition_number(&self) -> f64 {
+        let mut columns: Vec<Vec<f64>> = Vec::with_capacity(self.degree + 1);
+        let mut column: Vec<f64> = self.weights.iter().map(|w| w.sqrt()).collect();
+        for _ in 0..=self.degree {
+            let next = column.iter().zip(&self.xs).map(|(c, x)| c * x).collect();
+            columns.push(std::mem::replace(&mut column, next));
+        }
+
+        // rotates pairs of columns until they are all orthogonal, their norms are then the singular values
+        let dot = |a: &[f64], b: &[f64]| a.iter().zip(b).map(|(a, b)| a * b).sum::<f64>();
+        for _ in 0..JACOBI_SWEEPS {
+            let mut rotated = false;
+            for p in 0..columns.len() {
+                for q in p + 1..columns.len() {
+                    let alpha = dot(&columns[p], &columns[p]);
+                    let beta = dot(&columns[q], &columns[q]);
+                    let gamma = dot(&columns[p], &columns[q]);
+                    if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+                    let zeta = (beta - alpha) / (2.0 * gamma);
+                    let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                    let c = 1.0 / (1.0 + t * t).sqrt();
+                    let s = c * t;
+                    let (left, right) = columns.split_at_mut(q);
+                    for (a, b) in left[p].iter_mut().zip(right[0].iter_mut()) {
+                        (*a, *b) = (c * *a - s * *b, s * *a + c * *b);
+                    }
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let singular_values: Vec<f64> = columns.iter().map(|c| dot(c, c).sqrt()).collect();
+        let largest = singular_values.iter().cloned().fold(0.0, f64::max);
+        let smallest = singular_values
+            .iter()
+            .cloned()
+            .fold(f64::INFINITY, f64::min);
+        largest / smallest
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Returns the polynomial of degree at most `degree` minimizing `sum w_i (y_i - p(x_i))^2`,
+    /// with the weights `w_i` all `1` if `weights` is `None`. The weights are expected to be positive.
+    ///
+    /// The polynomials orthogonal for the weighted inner product on the points are built with their three-term
+    /// recurrence, and the data is projected onto them one at a time, which avoids the ill-conditioned normal equations.
+    /// # Panics
+    /// Panics if `xs`, `ys` and `weights` do not have the same length,
+    /// or if there are no more distinct points (with non-zero weights) than `degree`.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::RationalNumber;
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let r = |n, d| RationalNumber::new(n, d);
+    /// let xs = [r(0, 1), r(1, 1), r(2, 1)];
+    /// let ys = [r(0, 1), r(1, 1), r(3, 1)];
+    /// let fit = Polynomial::fit(&xs, &ys, 1, None);
+    /// assert_eq!(fit.polynomial(), &Polynomial::from_vector(vec![r(-1, 6), r(3, 2)]));
+    /// assert_eq!(fit.residuals(), &[r(1, 6), r(-1, 3), r(1, 6)]);
+    /// assert_eq!(fit.residual_sum_of_squares(), r(1, 6));
+    /// ```
+    pub fn fit(xs: &[T], ys: &[T], degree: usize, weights: Option<&[T]>) -> LeastSquaresFit<T>
+    where
+        T: PartialEq,
+    {
+        assert!(xs.len() == ys.len(), "xs and ys must have the same length");
+        let weights = match weights {
+            Some(weights) => {
+                assert!(
+                    weights.len() == xs.len(),
+                    "there must be one weight per point"
+                );
+                weights.to_vec()
+            }
+            None => vec![T::one(); xs.len()],
+        };
+        // checked up front, since in floating point the norms below only vanish up to round-off
+        let mut distinct: Vec<&T> = Vec::new();
+        for (x, w) in xs.iter().zip(&weights) {
+            if distinct.len() > degree {
+                break;
+            }
+            if !w.is_zero() && !distinct.contains(&x) {
+                distinct.push(x);
+            }
+        }
+        assert!(
+            distinct.len() > degree,
+            "the degree must be less than the number of distinct points"
+        );
+
+        let inner = |a: &[T], b: &[T]| {
+            a.iter()
+                .zip(b)
+                .zip(&weights)
+                .fold(T::zero(), |sum, ((a, b), w)| {
+                    sum + w.clone() * a.clone() * b.clone()
+                })
+        };
+        let combine = |a: &[T], b: &[T], f: &dyn Fn(T, T) -> T| -> Vec<T> {
+            a.iter()
+                .zip(b)
+                .map(|(a, b)| f(a.clone(), b.clone()))
+                .collect()
+        };
+
+        // q_(k+1) = (x - alpha_k) q_k - beta_k q_(k-1), kept both as polynomials and as values at the points
+        let mut polynomial = Polynomial::zero();
+        let mut residual = ys.to_vec();
+        let mut previous = (Polynomial::zero(), vec![T::zero(); xs.len()], T::one());
+        let mut current = (
+            Polynomial::from_constant(T::one()),
+            vec![T::one(); xs.len()],
+        );
+        for k in 0..=degree {
+            let norm = inner(&current.1, &current.1);
+            assert!(
+                !norm.is_zero(),
+                "the degree must be less than the number of distinct points"
+            );
+            // projecting the remaining residual rather than the data keeps the coefficients accurate in floating point
+            let coefficient = inner(&residual, &current.1) / norm.clone();
+            residual = combine(&residual, &current.1, &|r, q| r - coefficient.clone() * q);
+            polynomial = polynomial + current.0.clone() * coefficient;
+            if k == degree {
+                break;
+            }
+
+            let x_values = combine(xs, &current.1, &|x, q| x * q);
+            let alpha = inner(&x_values, &current.1) / norm.clone();
+            let beta = norm.clone() / previous.2.clone();
+            let next_values = combine(&x_values, &current.1, &|xq, q| xq - alpha.clone() * q);
+            let next_values = combine(&next_values, &previous.1, &|v, p| v - beta.clone() * p);
+            let next = current.0.clone()
+                * Polynomial::from_vector(vec![T::zero() - alpha, T::one()])
+                - previous.0.clone() * beta;
+            let (polynomial, values) = std::mem::replace(&mut current, (next, next_values));
+            previous = (polynomial, values, norm);
+        }
+
+        let residuals = xs
+            .iter()
+            .zip(ys)
+            .map(|(x, y)| y.clone() - polynomial.eval(x.clone()))
+            .collect();
+        LeastSquaresFit {
+            polynomial,
+            xs: xs.to_vec(),
+            weights,
+            degree,
+            residuals,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coefficients::RationalNumber;
+
+    fn r(n: i64, d: i64) -> RationalNumber {
+        RationalNumber::new(n, d)
+    }
+
+    #[test]
+    fn test_weighted_fit_is_exact() {
+        let xs: Vec<RationalNumber> = (-3..4).map(|x| r(x, 1)).collect();
+        let ys: Vec<RationalNumber> = [5, -1, 2, 0, 3, 7, -4].iter().map(|&y| r(y, 1)).collect();
+        let weights: Vec<RationalNumber> = [1, 2, 1, 3, 1, 1, 2].iter().map(|&w| r(w, 1)).collect();
+        let fit = Polynomial::fit(&xs, &ys, 2, Some(&weights));
+        assert!(fit.polynomial().degree() <= 2);
+        // the weighted residual is orthogonal to 1, x and x^2
+        for power in 0..3 {
+            let moment =
+                xs.iter()
+                    .zip(fit.residuals())
+                    .zip(&weights)
+                    .fold(r(0, 1), |sum, ((x, res), w)| {
+                        let mut term = *w * *res;
+                        for _ in 0..power {
+                            term = term * *x;
+                        }
+                        sum + term
+                    });
+            assert_eq!(moment, r(0, 1));
+        }
+    }
+
+    #[test]
+    fn test_interpolating_fit() {
+        let xs = [r(0, 1), r(1, 1), r(3, 1)];
+        let ys = [r(1, 1), r(-2, 1), r(4, 1)];
+        let fit = Polynomial::fit(&xs, &ys, 2, None);
+        assert_eq!(
+            fit.polynomial(),
+            &Polynomial::interpolate(&[(xs[0], ys[0]), (xs[1], ys[1]), (xs[2], ys[2])])
+        );
+        assert!(fit.residuals().iter().all(|res| res.is_zero()));
+    }
+
+    #[test]
+    fn test_fit_f64() {
+        let p = Polynomial::from_vector(vec![0.5, -1.0, 2.0, 0.25]);
+        let xs: Vec<f64> = (0..20).map(|i| -1.0 + 0.1 * i as f64).collect();
+        let ys: Vec<f64> = xs.iter().map(|&x| p.eval(x)).collect();
+        let fit = Polynomial::fit(&xs, &ys, 5, None);
+        let coefficients = fit.polynomial().coefficients();
+        for (k, expected) in [0.5, -1.0, 2.0, 0.25, 0.0, 0.0].iter().enumerate() {
+            assert!((coefficients[k] - expected).abs() < 1e-10);
+        }
+        assert!(fit.residual_sum_of_squares() < 1e-20);
+        assert!(fit.condition_number() > 1.0 && fit.condition_number() < 1e3);
+    }
+
+    #[test]
+    fn test_condition_number_grows() {
+        let xs: Vec<f64> = (0..30).map(|i| 10.0 + i as f64 / 29.0).collect();
+        let ys = vec![0.0; 30];
+        let low = Polynomial::fit(&xs, &ys, 1, None).condition_number();
+        let high = Polynomial::fit(&xs, &ys, 4, None).condition_number();
+        assert!(low > 10.0);
+        assert!(high > 1e6 * low);
+    }
+
+    #[test]
+    #[should_panic(expected = "the degree must be less than the number of distinct points")]
+    fn test_too_few_points() {
+        Polynomial::fit(&[r(1, 1), r(1, 1), r(2, 1)], &[r(0, 1); 3], 2, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "the degree must be less than the number of distinct points")]
+    fn test_too_few_points_f64() {
+        // the norm of the cubic orthogonal polynomial is only round-off here, not zero
+        Polynomial::fit(&[0.1, 0.7, 1.3], &[1.0, 2.0, 0.5], 3, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "the degree must be less than the number of distinct points")]
+    fn test_points_with_zero_weight() {
+        Polynomial::fit(
+            &[0.0, 1.0, 2.0],
+            &[1.0, 2.0, 0.5],
+            2,
+            Some(&[1.0, 0.0, 1.0]),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "there must be one weight per point")]
+    fn test_weights_length() {
+        Polynomial::fit(&[1.0, 2.0], &[1.0, 2.0], 1, Some(&[1.0]));
+    }
+}
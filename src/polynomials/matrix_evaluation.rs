@@ -0,0 +1,147 @@
+#![forbid(unsafe_code)]
+//! # Evaluation at matrices
+//!
+//! Multiplying two matrices is far more expensive than multiplying a matrix by a scalar,
+//! so these routines save matrix products at the expense of scalar ones.
+//!
+//! The Paterson–Stockmeyer scheme follows Paterson and Stockmeyer, "On the number of nonscalar
+//! multiplications necessary to evaluate polynomials" (1973).
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use num::{One, Zero};
+
+use super::Polynomial;
+use crate::coefficients::SquareMatrix;
+
+impl<T> Polynomial<T>
+where
+    T: One + Zero + Clone,
+{
+    /// Evaluate the polynomial at a given value with the Paterson–Stockmeyer scheme.
+    /// The result is the same as the one of [`Polynomial::eval`], but a polynomial of degree `n`
+    /// only takes about `2 sqrt(n)` multiplications of two values of type `U`,
+    /// the other multiplications being by coefficients.
+    /// This pays off when `U` is a matrix.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::SquareMatrix;
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let p = Polynomial::from_vector((1..=10).collect());
+    /// let m = SquareMatrix::new([[1, 2], [0, 1]]);
+    /// assert_eq!(p.eval_matrix(m.clone()), p.eval(m));
+    /// ```
+    pub fn eval_matrix<U>(&self, x: U) -> U
+    where
+        U: Clone + Zero + Add<Output = U> + Mul<Output = U> + One + Mul<T, Output = U>,
+    {
+        let degree = self.degree();
+        if degree == 0 {
+            return U::one() * self.coefficients()[0].clone();
+        }
+
+        // the block size s = ceil(sqrt(n + 1)) balances the s - 1 products for the powers
+        // against the (n + 1) / s products of Horner's scheme in x^s
+        let mut block = 1;
+        while block * block < degree + 1 {
+            block += 1;
+        }
+
+        // powers[i] = x^i for 0 <= i <= s
+        let mut powers = vec![U::one(), x.clone()];
+        for _ in 2..=block {
+            let power = powers[powers.len() - 1].clone() * x.clone();
+            powers.push(power);
+        }
+        let stride = powers[block].clone();
+
+        let block_sum = |chunk: &[T]| {
+            let mut sum = U::zero();
+            for (power, coeff) in powers.iter().zip(chunk) {
+                sum = sum + power.clone() * coeff.clone();
+            }
+            sum
+        };
+
+        let mut chunks = self.coefficients().chunks(block).rev();
+        let mut result = chunks.next().map(block_sum).unwrap_or_else(U::zero);
+        for chunk in chunks {
+            result = result * stride.clone() + block_sum(chunk);
+        }
+        result
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Copy + Zero + One,
+{
+    /// Evaluate the polynomial at a matrix `A` after reducing it modulo the characteristic polynomial of `A`.
+    /// By the Cayley–Hamilton theorem the result is unchanged, but only a polynomial of degree below `N`
+    /// is left to evaluate with [`Polynomial::eval_matrix`], whatever the degree of the polynomial.
+    /// The coefficients have to form a field; with `f64` the reduction may lose accuracy
+    /// when the characteristic polynomial is ill-conditioned.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::{RationalNumber, SquareMatrix};
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let r = |n| RationalNumber::new(n, 1);
+    /// let p = Polynomial::<RationalNumber>::x_pow(100);
+    /// let m = SquareMatrix::new([[r(1), r(1)], [r(0), r(1)]]);
+    /// assert_eq!(p.eval_matrix_reduced(&m), SquareMatrix::new([[r(1), r(100)], [r(0), r(1)]]));
+    /// ```
+    pub fn eval_matrix_reduced<const N: usize>(
+        &self,
+        m: &SquareMatrix<T, N>,
+    ) -> SquareMatrix<T, N> {
+        let remainder = self.div_rem(&m.characteristic_polynomial()).1;
+        remainder.eval_matrix(m.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coefficients::{Matrix, RationalNumber};
+
+    fn r(n: i64) -> RationalNumber {
+        RationalNumber::new(n, 1)
+    }
+
+    #[test]
+    fn test_eval_matrix_matches_horner() {
+        let m = SquareMatrix::new([[1, -1, 0], [2, 0, 1], [0, 1, -1]]);
+        for degree in 0..20 {
+            let p = Polynomial::from_vector((0..=degree).map(|it| it as i64 - 7).collect());
+            assert_eq!(p.eval_matrix(m.clone()), p.eval(m.clone()));
+        }
+    }
+
+    #[test]
+    fn test_eval_matrix_scalars() {
+        let p = Polynomial::from_vector(vec![3, 0, 0, -2, 0, 1]);
+        for x in -3..=3 {
+            assert_eq!(p.eval_matrix(x), p.eval(x));
+        }
+        assert_eq!(Polynomial::from_constant(5).eval_matrix(2), 5);
+    }
+
+    #[test]
+    fn test_eval_matrix_dynamic() {
+        let p = Polynomial::from_vector(vec![1, 2, 3, 4, 5, 6, 7]);
+        let m = Matrix::from_rows(vec![vec![0, 1], vec![-1, 0]]);
+        assert_eq!(p.eval_matrix(m.clone()), p.eval(m));
+    }
+
+    #[test]
+    fn test_eval_matrix_reduced() {
+        let m = SquareMatrix::new([[r(2), r(1), r(0)], [r(0), r(1), r(-1)], [r(1), r(0), r(3)]]);
+        let p = Polynomial::from_vector((0..30).map(|it| r(it % 5 - 2)).collect());
+        assert_eq!(p.eval_matrix_reduced(&m), p.eval(m.clone()));
+        // the characteristic polynomial itself evaluates to zero
+        assert!(m
+            .characteristic_polynomial()
+            .eval_matrix_reduced(&m)
+            .is_zero());
+    }
+}
@@ -1,4 +1,32 @@
 #![forbid(unsafe_code)]
 
+pub mod approximation;
+pub mod float_evaluation;
+pub mod interpolation;
+pub mod laurent_polynomial;
+pub mod lazy_series;
+pub mod least_squares;
+pub mod matrix_evaluation;
+pub mod multipoint;
+pub mod orthogonal;
+pub mod pade;
+pub mod partial_fractions;
 pub mod polynomial;
+pub mod polynomial_matrices;
+pub mod power_series;
+pub mod range_evaluation;
+pub mod rational_function;
+pub mod roots;
+pub use approximation::Approximation;
+pub use interpolation::NewtonInterpolator;
+pub use laurent_polynomial::LaurentPolynomial;
+pub use lazy_series::LazySeries;
+pub use least_squares::LeastSquaresFit;
+pub use multipoint::SubproductTree;
+pub use orthogonal::OrthogonalFamily;
+pub use pade::{ContinuedFraction, PadeApproximant};
+pub use partial_fractions::{LinearPartialFractions, PartialFraction, PartialFractions, Pole};
 pub use polynomial::Polynomial;
+pub use polynomial_matrices::{HermiteNormalForm, SmithNormalForm};
+pub use power_series::PowerSeries;
+pub use rational_function::RationalFunction;
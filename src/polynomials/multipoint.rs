@@ -0,0 +1,237 @@
+#![forbid(unsafe_code)]
+//! # Multipoint evaluation and interpolation
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use num::{One, Zero};
+
+use super::Polynomial;
+
+/// A subproduct tree over a set of points `x_0, ..., x_{n-1}`.
+/// The leaves are the linear polynomials `x - x_i` and every inner node is the product of its children.
+/// Evaluation and interpolation descend and ascend the tree, so that they take `O(M(n) log n)` operations,
+/// where `M(n)` is the cost of multiplying two polynomials of degree `n`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubproductTree<T>
+where
+    T: One + Zero + Clone,
+{
+    points: Vec<T>,
+    /// `levels[0]` holds the leaves, the last level holds the root.
+    levels: Vec<Vec<Polynomial<T>>>,
+}
+
+impl<T> SubproductTree<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Builds the subproduct tree over the given points.
+    pub fn new(points: &[T]) -> Self {
+        let mut levels = vec![points
+            .iter()
+            .map(|x| Polynomial::x() - x.clone())
+            .collect::<Vec<_>>()];
+        while levels[levels.len() - 1].len() > 1 {
+            let level = levels[levels.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => left.clone() * right.clone(),
+                    _ => pair[0].clone(),
+                })
+                .collect();
+            levels.push(level);
+        }
+        Self {
+            points: points.to_vec(),
+            levels,
+        }
+    }
+
+    /// Returns the points the tree was built over.
+    pub fn points(&self) -> &[T] {
+        &self.points
+    }
+
+    /// Returns the root of the tree, i.e. the product `(x - x_0) * ... * (x - x_{n-1})`.
+    pub fn root(&self) -> Polynomial<T> {
+        match self.levels[self.levels.len() - 1].first() {
+            Some(root) => root.clone(),
+            None => Polynomial::from_constant(T::one()),
+        }
+    }
+
+    /// Evaluates `p` at all the points of the tree using the remainder tree.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::{Polynomial, SubproductTree};
+    /// let tree = SubproductTree::new(&[0.0, 1.0, 2.0]);
+    /// let p = Polynomial::from_vector(vec![1.0, 2.0, 3.0]); // 1 + 2x + 3x^2
+    /// assert_eq!(tree.eval(&p), vec![1.0, 6.0, 17.0]);
+    /// ```
+    pub fn eval(&self, p: &Polynomial<T>) -> Vec<T> {
+        if self.points.is_empty() {
+            return Vec::new();
+        }
+
+        let mut remainders = vec![p.div_rem(&self.root()).1];
+        for level in self.levels.iter().rev().skip(1) {
+            remainders = level
+                .iter()
+                .enumerate()
+                .map(|(it, node)| remainders[it / 2].div_rem(node).1)
+                .collect();
+        }
+        remainders
+            .into_iter()
+            .map(|r| r.coefficients()[0].clone())
+            .collect()
+    }
+
+    /// Returns the unique polynomial of minimal degree taking `values[i]` at the `i`-th point of the tree.
+    /// # Panics
+    /// Panics if the number of values differs from the number of points, or if the points are not distinct.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::{Polynomial, SubproductTree};
+    /// let tree = SubproductTree::new(&[0.0, 1.0, 2.0]);
+    /// let p = tree.interpolate(&[1.0, 6.0, 17.0]);
+    /// assert_eq!(p, Polynomial::from_vector(vec![1.0, 2.0, 3.0]));
+    /// ```
+    pub fn interpolate(&self, values: &[T]) -> Polynomial<T> {
+        assert_eq!(
+            values.len(),
+            self.points.len(),
+            "the number of values must match the number of points"
+        );
+        if self.points.is_empty() {
+            return Polynomial::zero();
+        }
+
+        // Lagrange weights y_i / m'(x_i), where m is the root of the tree.
+        let derivatives = self.eval(&self.root().derivative());
+        let mut combinations: Vec<Polynomial<T>> = values
+            .iter()
+            .zip(derivatives)
+            .map(|(value, derivative)| {
+                assert!(
+                    !derivative.is_zero(),
+                    "interpolation nodes must be distinct"
+                );
+                Polynomial::from_constant(value.clone() / derivative)
+            })
+            .collect();
+
+        for level in &self.levels[..self.levels.len() - 1] {
+            combinations = combinations
+                .chunks(2)
+                .zip(level.chunks(2))
+                .map(|(combination, nodes)| match (combination, nodes) {
+                    ([left, right], [left_node, right_node]) => {
+                        left.clone() * right_node.clone() + right.clone() * left_node.clone()
+                    }
+                    _ => combination[0].clone(),
+                })
+                .collect();
+        }
+        combinations.swap_remove(0)
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Evaluates the polynomial at many points at once using a subproduct tree.
+    /// This pays off for many points once the polynomial multiplication is fast,
+    /// use [`SubproductTree::eval`] directly to reuse the tree for several polynomials.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let p = Polynomial::from_vector(vec![1.0, 2.0, 3.0]); // 1 + 2x + 3x^2
+    /// assert_eq!(p.eval_many(&[0.0, 1.0, 2.0]), vec![1.0, 6.0, 17.0]);
+    /// ```
+    pub fn eval_many(&self, points: &[T]) -> Vec<T> {
+        SubproductTree::new(points).eval(self)
+    }
+
+    /// Returns the unique polynomial of minimal degree passing through the given `(x, y)` points.
+    /// Unlike [`Polynomial::interpolate`], the polynomial is assembled along a subproduct tree.
+    /// # Panics
+    /// Panics if two points share the same `x`.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let p = Polynomial::interpolate_fast(&[(0.0, 1.0), (1.0, 3.0), (2.0, 7.0)]);
+    /// assert_eq!(p, Polynomial::from_vector(vec![1.0, 1.0, 1.0])); // 1 + x + x^2
+    /// ```
+    pub fn interpolate_fast(points: &[(T, T)]) -> Self {
+        let (xs, ys): (Vec<T>, Vec<T>) = points.iter().cloned().unzip();
+        SubproductTree::new(&xs).interpolate(&ys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coefficients::RationalNumber;
+
+    fn r(n: i64) -> RationalNumber {
+        RationalNumber::new(n, 1)
+    }
+
+    #[test]
+    fn test_subproduct_tree_root() {
+        let tree = SubproductTree::new(&[r(1), r(2), r(3)]);
+        assert_eq!(
+            tree.root(),
+            Polynomial::from_vector(vec![r(-6), r(11), r(-6), r(1)])
+        );
+        assert_eq!(tree.points(), &[r(1), r(2), r(3)]);
+        assert_eq!(
+            SubproductTree::<RationalNumber>::new(&[]).root(),
+            Polynomial::from_constant(r(1))
+        );
+    }
+
+    #[test]
+    fn test_eval_many() {
+        let p = Polynomial::from_vector(vec![r(3), r(-1), RationalNumber::new(2, 3), r(0), r(5)]);
+        let points: Vec<_> = (-3..4).map(r).collect();
+        let expected: Vec<_> = points.iter().map(|&x| p.eval(x)).collect();
+        assert_eq!(p.eval_many(&points), expected);
+        assert!(p.eval_many(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_eval_many_more_points_than_degree() {
+        let p = Polynomial::from_vector(vec![r(1), r(1)]);
+        let points: Vec<_> = (0..10).map(r).collect();
+        assert_eq!(p.eval_many(&points), (1..11).map(r).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_interpolate_fast() {
+        let points: Vec<_> = [(1, 4), (-2, 0), (3, 3), (5, -1), (0, 2)]
+            .into_iter()
+            .map(|(x, y)| (r(x), r(y)))
+            .collect();
+        let p = Polynomial::interpolate_fast(&points);
+        assert_eq!(p, Polynomial::interpolate(&points));
+        assert!(Polynomial::<RationalNumber>::interpolate_fast(&[]).is_zero());
+    }
+
+    #[test]
+    fn test_tree_reuse() {
+        let tree = SubproductTree::new(&[r(0), r(1), r(2), r(3)]);
+        let p = Polynomial::from_vector(vec![r(0), r(0), r(1)]);
+        let values = tree.eval(&p);
+        assert_eq!(values, vec![r(0), r(1), r(4), r(9)]);
+        assert_eq!(tree.interpolate(&values), p);
+    }
+
+    #[test]
+    #[should_panic(expected = "interpolation nodes must be distinct")]
+    fn test_interpolate_fast_repeated_nodes() {
+        Polynomial::interpolate_fast(&[(r(1), r(1)), (r(2), r(2)), (r(1), r(3))]);
+    }
+}
@@ -0,0 +1,439 @@
+#![forbid(unsafe_code)]
+//! # Orthogonal polynomials
+//!
+//! The classical families of orthogonal polynomials, computed from their three-term recurrences
+//! `P_n = (a_n x + b_n) P_(n-1) - c_n P_(n-2)`, and the change of basis between the monomials and a family.
+//!
+//! The Chebyshev and Hermite polynomials have integer coefficients and can be built over any ring, e.g. `i64`.
+//! The other families, and the change of basis for every family, need a field such as `RationalNumber` or `f64`.
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use num::{One, Zero};
+
+use super::Polynomial;
+
+/// A classical family of orthogonal polynomials, with its parameters.
+#[derive(Clone, Debug, PartialEq)]
+pub enum OrthogonalFamily<T> {
+    /// The Chebyshev polynomials of the first kind `T_n`, orthogonal on `[-1, 1]` for the weight `(1 - x^2)^(-1/2)`.
+    ChebyshevT,
+    /// The Chebyshev polynomials of the second kind `U_n`, orthogonal on `[-1, 1]` for the weight `(1 - x^2)^(1/2)`.
+    ChebyshevU,
+    /// The Legendre polynomials `P_n`, orthogonal on `[-1, 1]` for the weight `1`.
+    Legendre,
+    /// The physicists' Hermite polynomials `H_n`, orthogonal on the real line for the weight `exp(-x^2)`.
+    Hermite,
+    /// The probabilists' Hermite polynomials `He_n`, orthogonal on the real line for the weight `exp(-x^2 / 2)`.
+    HermiteE,
+    /// The Laguerre polynomials `L_n`, orthogonal on `[0, inf)` for the weight `exp(-x)`.
+    Laguerre,
+    /// The generalized Laguerre polynomials `L_n^(alpha)`, orthogonal on `[0, inf)` for the weight `x^alpha exp(-x)`.
+    GeneralizedLaguerre(T),
+    /// The Jacobi polynomials `P_n^(alpha, beta)`, orthogonal on `[-1, 1]` for the weight `(1 - x)^alpha (1 + x)^beta`.
+    Jacobi(T, T),
+    /// The Gegenbauer polynomials `C_n^(lambda)`, orthogonal on `[-1, 1]` for the weight `(1 - x^2)^(lambda - 1/2)`.
+    Gegenbauer(T),
+}
+
+/// Returns `k` as an element of `T`.
+fn integer<T>(k: usize) -> T
+where
+    T: Zero + One + Add<Output = T>,
+{
+    (0..k).fold(T::zero(), |sum, _| sum + T::one())
+}
+
+/// Returns `P_0 = 1, P_1 = first, ..., P_n`, where `step(k)` gives the coefficients `(a_k, b_k, c_k)`
+/// of the recurrence `P_k = (a_k x + b_k) P_(k-1) - c_k P_(k-2)` for `k >= 2`.
+fn recurrence<T>(
+    n: usize,
+    first: Polynomial<T>,
+    step: impl Fn(T) -> (T, T, T),
+) -> Vec<Polynomial<T>>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    let mut polynomials = vec![Polynomial::from_constant(T::one()), first];
+    for k in 2..=n {
+        let (a, b, c) = step(integer(k));
+        let next = polynomials[k - 1].clone() * Polynomial::from_vector(vec![b, a])
+            - polynomials[k - 2].clone() * c;
+        polynomials.push(next);
+    }
+    polynomials.truncate(n + 1);
+    polynomials
+}
+
+/// Returns `T_0, ..., T_n`: `T_k = 2x T_(k-1) - T_(k-2)`.
+fn chebyshev_t<T>(n: usize) -> Vec<Polynomial<T>>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    let two: T = integer(2);
+    recurrence(n, Polynomial::x(), |_| (two.clone(), T::zero(), T::one()))
+}
+
+/// Returns `U_0, ..., U_n`: `U_k = 2x U_(k-1) - U_(k-2)`.
+fn chebyshev_u<T>(n: usize) -> Vec<Polynomial<T>>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    let two: T = integer(2);
+    recurrence(n, Polynomial::x() * two.clone(), |_| {
+        (two.clone(), T::zero(), T::one())
+    })
+}
+
+/// Returns `H_0, ..., H_n`: `H_k = 2x H_(k-1) - 2(k-1) H_(k-2)`.
+fn hermite<T>(n: usize) -> Vec<Polynomial<T>>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    let two: T = integer(2);
+    recurrence(n, Polynomial::x() * two.clone(), |k| {
+        (two.clone(), T::zero(), two.clone() * (k - T::one()))
+    })
+}
+
+/// Returns `He_0, ..., He_n`: `He_k = x He_(k-1) - (k-1) He_(k-2)`.
+fn hermite_e<T>(n: usize) -> Vec<Polynomial<T>>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    recurrence(n, Polynomial::x(), |k| (T::one(), T::zero(), k - T::one()))
+}
+
+impl<T> OrthogonalFamily<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Returns the polynomials of the family of degrees `0` up to `n`, with the standard normalizations.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::RationalNumber;
+    /// use rust_polynomials_lib::polynomials::{OrthogonalFamily, Polynomial};
+    /// let r = |n, d| RationalNumber::new(n, d);
+    /// let legendre = OrthogonalFamily::Legendre.polynomials(2);
+    /// assert_eq!(legendre[1], Polynomial::x());
+    /// assert_eq!(legendre[2], Polynomial::from_vector(vec![r(-1, 2), r(0, 1), r(3, 2)]));
+    /// ```
+    pub fn polynomials(&self, n: usize) -> Vec<Polynomial<T>> {
+        let one = T::one();
+        let two: T = integer(2);
+        match self {
+            Self::ChebyshevT => chebyshev_t(n),
+            Self::ChebyshevU => chebyshev_u(n),
+            Self::Hermite => hermite(n),
+            Self::HermiteE => hermite_e(n),
+            // k P_k = (2k - 1) x P_(k-1) - (k - 1) P_(k-2)
+            Self::Legendre => recurrence(n, Polynomial::x(), |k| {
+                (
+                    (two.clone() * k.clone() - one.clone()) / k.clone(),
+                    T::zero(),
+                    (k.clone() - one.clone()) / k,
+                )
+            }),
+            Self::Laguerre => Self::GeneralizedLaguerre(T::zero()).polynomials(n),
+            // k L_k = (2k - 1 + alpha - x) L_(k-1) - (k - 1 + alpha) L_(k-2)
+            Self::GeneralizedLaguerre(alpha) => recurrence(
+                n,
+                Polynomial::from_vector(vec![one.clone() + alpha.clone(), T::zero() - one.clone()]),
+                |k| {
+                    (
+                        T::zero() - one.clone() / k.clone(),
+                        (two.clone() * k.clone() - one.clone() + alpha.clone()) / k.clone(),
+                        (k.clone() - one.clone() + alpha.clone()) / k,
+                    )
+                },
+            ),
+            // k C_k = 2 (k + lambda - 1) x C_(k-1) - (k + 2 lambda - 2) C_(k-2)
+            Self::Gegenbauer(lambda) => {
+                recurrence(n, Polynomial::x() * (two.clone() * lambda.clone()), |k| {
+                    (
+                        two.clone() * (k.clone() + lambda.clone() - one.clone()) / k.clone(),
+                        T::zero(),
+                        (k.clone() + two.clone() * lambda.clone() - two.clone()) / k,
+                    )
+                })
+            }
+            Self::Jacobi(alpha, beta) => {
+                let sum = alpha.clone() + beta.clone();
+                let first = Polynomial::from_vector(vec![
+                    (alpha.clone() - beta.clone()) / two.clone(),
+                    (sum.clone() + two.clone()) / two.clone(),
+                ]);
+                // 2k (k + s) (2k + s - 2) P_k = (2k + s - 1) ((2k + s) (2k + s - 2) x + alpha^2 - beta^2) P_(k-1)
+                //                               - 2 (k + alpha - 1) (k + beta - 1) (2k + s) P_(k-2)
+                recurrence(n, first, |k| {
+                    let m = two.clone() * k.clone() + sum.clone();
+                    let denominator = two.clone()
+                        * k.clone()
+                        * (k.clone() + sum.clone())
+                        * (m.clone() - two.clone());
+                    (
+                        (m.clone() - one.clone()) * m.clone() * (m.clone() - two.clone())
+                            / denominator.clone(),
+                        (m.clone() - one.clone())
+                            * (alpha.clone() * alpha.clone() - beta.clone() * beta.clone())
+                            / denominator.clone(),
+                        two.clone()
+                            * (k.clone() + alpha.clone() - one.clone())
+                            * (k - one.clone() + beta.clone())
+                            * m
+                            / denominator,
+                    )
+                })
+            }
+        }
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Returns the Chebyshev polynomial of the first kind `T_n`, with `T_n(cos t) = cos(n t)`.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// assert_eq!(Polynomial::<i64>::chebyshev_t(4), Polynomial::from_vector(vec![1, 0, -8, 0, 8]));
+    /// ```
+    pub fn chebyshev_t(n: usize) -> Self {
+        chebyshev_t(n).pop().unwrap()
+    }
+
+    /// Returns the Chebyshev polynomial of the second kind `U_n`, with `U_n(cos t) sin t = sin((n + 1) t)`.
+    pub fn chebyshev_u(n: usize) -> Self {
+        chebyshev_u(n).pop().unwrap()
+    }
+
+    /// Returns the physicists' Hermite polynomial `H_n`, whose leading coefficient is `2^n`.
+    pub fn hermite(n: usize) -> Self {
+        hermite(n).pop().unwrap()
+    }
+
+    /// Returns the probabilists' Hermite polynomial `He_n`, which is monic.
+    pub fn hermite_e(n: usize) -> Self {
+        hermite_e(n).pop().unwrap()
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Returns the Legendre polynomial `P_n`, normalized by `P_n(1) = 1`.
+    pub fn legendre(n: usize) -> Self {
+        OrthogonalFamily::Legendre.polynomials(n).pop().unwrap()
+    }
+
+    /// Returns the Laguerre polynomial `L_n`, normalized by `L_n(0) = 1`.
+    pub fn laguerre(n: usize) -> Self {
+        OrthogonalFamily::Laguerre.polynomials(n).pop().unwrap()
+    }
+
+    /// Returns the generalized Laguerre polynomial `L_n^(alpha)`, where `alpha > -1` for orthogonality.
+    pub fn generalized_laguerre(n: usize, alpha: T) -> Self {
+        OrthogonalFamily::GeneralizedLaguerre(alpha)
+            .polynomials(n)
+            .pop()
+            .unwrap()
+    }
+
+    /// Returns the Jacobi polynomial `P_n^(alpha, beta)`, where `alpha, beta > -1` for orthogonality.
+    /// It is normalized by `P_n^(alpha, beta)(1) = binomial(n + alpha, n)`.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::RationalNumber;
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let r = |n, d| RationalNumber::new(n, d);
+    /// let p = Polynomial::jacobi(3, r(2, 1), r(1, 2));
+    /// assert_eq!(p.eval(r(1, 1)), r(10, 1));
+    /// ```
+    pub fn jacobi(n: usize, alpha: T, beta: T) -> Self {
+        OrthogonalFamily::Jacobi(alpha, beta)
+            .polynomials(n)
+            .pop()
+            .unwrap()
+    }
+
+    /// Returns the Gegenbauer polynomial `C_n^(lambda)`, where `lambda > -1/2` for orthogonality.
+    pub fn gegenbauer(n: usize, lambda: T) -> Self {
+        OrthogonalFamily::Gegenbauer(lambda)
+            .polynomials(n)
+            .pop()
+            .unwrap()
+    }
+
+    /// Returns the coefficients `c_0, ..., c_d` of the polynomial of degree `d` in the basis of the family,
+    /// such that the polynomial is `c_0 P_0 + ... + c_d P_d`.
+    /// The leading terms are removed one at a time, which only needs the leading coefficients of the `P_k`.
+    /// The coefficients must form a field: over `i64` the divisions by these leading coefficients truncate.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::RationalNumber;
+    /// use rust_polynomials_lib::polynomials::{OrthogonalFamily, Polynomial};
+    /// let r = |n, d| RationalNumber::new(n, d);
+    /// // x^3 = (3 T_1 + T_3) / 4
+    /// let x3 = Polynomial::<RationalNumber>::x_pow(3);
+    /// let coefficients = x3.to_orthogonal_basis(&OrthogonalFamily::ChebyshevT);
+    /// assert_eq!(coefficients, vec![r(0, 1), r(3, 4), r(0, 1), r(1, 4)]);
+    /// assert_eq!(Polynomial::from_orthogonal_basis(&OrthogonalFamily::ChebyshevT, &coefficients), x3);
+    /// ```
+    pub fn to_orthogonal_basis(&self, family: &OrthogonalFamily<T>) -> Vec<T> {
+        let degree = self.degree();
+        let basis = family.polynomials(degree);
+        let mut remainder = self.clone();
+        let mut coefficients = vec![T::zero(); degree + 1];
+        for k in (0..=degree).rev() {
+            let leading = remainder
+                .coefficients()
+                .get(k)
+                .cloned()
+                .unwrap_or_else(T::zero);
+            if leading.is_zero() {
+                continue;
+            }
+            let coefficient = leading / basis[k].coefficients()[k].clone();
+            remainder = remainder - basis[k].clone() * coefficient.clone();
+            coefficients[k] = coefficient;
+        }
+        coefficients
+    }
+
+    /// Returns the polynomial `c_0 P_0 + ... + c_d P_d` in the monomial basis,
+    /// from its coefficients in the basis of the family.
+    pub fn from_orthogonal_basis(family: &OrthogonalFamily<T>, coefficients: &[T]) -> Self {
+        let basis = family.polynomials(coefficients.len().saturating_sub(1));
+        coefficients
+            .iter()
+            .zip(basis)
+            .fold(Self::zero(), |sum, (c, p)| sum + p * c.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coefficients::RationalNumber;
+
+    fn r(n: i64, d: i64) -> RationalNumber {
+        RationalNumber::new(n, d)
+    }
+
+    fn poly(coefficients: &[(i64, i64)]) -> Polynomial<RationalNumber> {
+        Polynomial::from_vector(coefficients.iter().map(|&(n, d)| r(n, d)).collect())
+    }
+
+    #[test]
+    fn test_integer_families() {
+        assert_eq!(
+            Polynomial::<i64>::chebyshev_t(0),
+            Polynomial::from_constant(1)
+        );
+        assert_eq!(
+            Polynomial::<i64>::chebyshev_u(3),
+            Polynomial::from_vector(vec![0, -4, 0, 8])
+        );
+        assert_eq!(
+            Polynomial::<i64>::hermite(3),
+            Polynomial::from_vector(vec![0, -12, 0, 8])
+        );
+        assert_eq!(
+            Polynomial::<i64>::hermite_e(4),
+            Polynomial::from_vector(vec![3, 0, -6, 0, 1])
+        );
+        // T_n(cos t) = cos(n t)
+        let t = 0.7f64;
+        assert!((Polynomial::<f64>::chebyshev_t(7).eval(t.cos()) - (7.0 * t).cos()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_field_families() {
+        assert_eq!(
+            Polynomial::legendre(3),
+            poly(&[(0, 1), (-3, 2), (0, 1), (5, 2)])
+        );
+        assert_eq!(Polynomial::laguerre(2), poly(&[(1, 1), (-2, 1), (1, 2)]));
+        // L_2^(alpha) = (alpha + 1)(alpha + 2) / 2 - (alpha + 2) x + x^2 / 2
+        assert_eq!(
+            Polynomial::generalized_laguerre(2, r(1, 2)),
+            poly(&[(15, 8), (-5, 2), (1, 2)])
+        );
+        assert_eq!(
+            Polynomial::gegenbauer(2, r(3, 1)),
+            poly(&[(-3, 1), (0, 1), (24, 1)])
+        );
+        for n in 0..6 {
+            assert_eq!(Polynomial::laguerre(n).eval(r(0, 1)), r(1, 1));
+            assert_eq!(Polynomial::legendre(n).eval(r(1, 1)), r(1, 1));
+        }
+    }
+
+    #[test]
+    fn test_special_cases() {
+        for n in 0..7 {
+            let legendre = Polynomial::legendre(n);
+            assert_eq!(Polynomial::jacobi(n, r(0, 1), r(0, 1)), legendre);
+            assert_eq!(Polynomial::gegenbauer(n, r(1, 2)), legendre);
+            assert_eq!(
+                Polynomial::gegenbauer(n, r(1, 1)),
+                Polynomial::chebyshev_u(n)
+            );
+            assert_eq!(
+                Polynomial::generalized_laguerre(n, r(0, 1)),
+                Polynomial::laguerre(n)
+            );
+        }
+    }
+
+    #[test]
+    fn test_jacobi_symmetry() {
+        // P_n^(alpha, beta)(-x) = (-1)^n P_n^(beta, alpha)(x)
+        let (alpha, beta) = (r(3, 2), r(-1, 3));
+        let x = r(2, 7);
+        for n in 0..6 {
+            let sign = if n % 2 == 0 { r(1, 1) } else { r(-1, 1) };
+            assert_eq!(
+                Polynomial::jacobi(n, alpha, beta).eval(r(0, 1) - x),
+                sign * Polynomial::jacobi(n, beta, alpha).eval(x)
+            );
+        }
+    }
+
+    #[test]
+    fn test_basis_round_trip() {
+        let p = poly(&[(3, 1), (-1, 2), (0, 1), (7, 3), (-2, 1), (1, 5)]);
+        let families = [
+            OrthogonalFamily::ChebyshevT,
+            OrthogonalFamily::ChebyshevU,
+            OrthogonalFamily::Legendre,
+            OrthogonalFamily::Hermite,
+            OrthogonalFamily::HermiteE,
+            OrthogonalFamily::Laguerre,
+            OrthogonalFamily::GeneralizedLaguerre(r(2, 3)),
+            OrthogonalFamily::Jacobi(r(1, 2), r(3, 1)),
+            OrthogonalFamily::Gegenbauer(r(5, 4)),
+        ];
+        for family in &families {
+            let coefficients = p.to_orthogonal_basis(family);
+            assert_eq!(coefficients.len(), 6);
+            assert_eq!(Polynomial::from_orthogonal_basis(family, &coefficients), p);
+        }
+        // the basis polynomials themselves have unit coordinates
+        let coefficients = Polynomial::<RationalNumber>::hermite(3)
+            .to_orthogonal_basis(&OrthogonalFamily::Hermite);
+        assert_eq!(coefficients, vec![r(0, 1), r(0, 1), r(0, 1), r(1, 1)]);
+    }
+
+    #[test]
+    fn test_f64_basis() {
+        let p = Polynomial::from_vector(vec![1.0, 2.0, 3.0]);
+        // 1 + 2x + 3x^2 = 2 P_0 + 2 P_1 + 2 P_2
+        let coefficients = p.to_orthogonal_basis(&OrthogonalFamily::Legendre);
+        for (c, e) in coefficients.iter().zip([2.0f64, 2.0, 2.0]) {
+            assert!((c - e).abs() < 1e-14);
+        }
+    }
+}
@@ -0,0 +1,392 @@
+#![forbid(unsafe_code)]
+//! # Padé approximants
+//!
+//! The `[m/n]` Padé approximant of a power series `f` is the rational function `P / Q` with `deg P <= m`
+//! and `deg Q <= n` whose expansion agrees with `f` up to `x^(m+n)`.
+//! It is read off the remainder sequence of the extended Euclidean algorithm applied to `x^(m+n+1)` and `f`.
+//!
+//! The continued fraction expansion of a series gives a sequence of such approximants, its convergents.
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use num::{One, Zero};
+
+use super::{Polynomial, PowerSeries, RationalFunction};
+
+/// A rational approximation `numerator / denominator` of a power series.
+/// The denominator is normalized to the constant term `1`,
+/// so that the approximant can be evaluated without any further division by its leading coefficient.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PadeApproximant<T>
+where
+    T: One + Zero + Clone,
+{
+    numerator: Polynomial<T>,
+    denominator: Polynomial<T>,
+}
+
+impl<T> PadeApproximant<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Returns the numerator.
+    pub fn numerator(&self) -> &Polynomial<T> {
+        &self.numerator
+    }
+
+    /// Returns the denominator, whose constant term is `1`.
+    pub fn denominator(&self) -> &Polynomial<T> {
+        &self.denominator
+    }
+
+    /// Evaluate the approximant at a given value, as the quotient of the evaluated numerator and denominator.
+    pub fn eval<U>(&self, x: U) -> U
+    where
+        U: Clone
+            + Zero
+            + One
+            + Add<Output = U>
+            + Mul<Output = U>
+            + Div<Output = U>
+            + Mul<T, Output = U>,
+    {
+        self.numerator.eval(x.clone()) / self.denominator.eval(x)
+    }
+
+    /// Returns the approximant as a reduced [`RationalFunction`].
+    /// The numerator and the denominator are already coprime, but the denominator is made monic.
+    pub fn to_rational_function(&self) -> RationalFunction<T> {
+        RationalFunction::new(self.numerator.clone(), self.denominator.clone())
+    }
+
+    /// Returns the approximant `numerator / denominator` after cancelling the common powers of `x`
+    /// and normalizing the constant term of the denominator to `1`.
+    fn normalized(numerator: Polynomial<T>, denominator: Polynomial<T>) -> Self {
+        let valuation = |p: &Polynomial<T>| p.coefficients().iter().position(|c| !c.is_zero());
+        // the denominator is never zero, and its gcd with the numerator is a power of x
+        let shift = match valuation(&numerator) {
+            Some(it) => it.min(valuation(&denominator).unwrap_or(0)),
+            None => valuation(&denominator).unwrap_or(0),
+        };
+        let drop = |p: Polynomial<T>| {
+            if p.coefficients().len() <= shift {
+                Polynomial::zero()
+            } else {
+                Polynomial::from_vector(p.coefficients()[shift..].to_vec())
+            }
+        };
+        let numerator = drop(numerator);
+        let denominator = drop(denominator);
+        let inverse = T::one() / denominator.coefficients()[0].clone();
+        Self {
+            numerator: numerator * inverse.clone(),
+            denominator: denominator * inverse,
+        }
+    }
+
+    /// Returns the `[m/n]` approximant of the series whose first `m + n + 1` coefficients are `coefficients`.
+    fn from_coefficients(coefficients: Vec<T>, m: usize, n: usize) -> Self {
+        let mut power = vec![T::zero(); m + n + 1];
+        power.push(T::one());
+
+        // every remainder r_k of the Euclidean algorithm satisfies r_k = t_k f mod x^(m+n+1),
+        // and the first one of degree at most m comes with deg t_k <= n
+        let (mut r0, mut r1) = (
+            Polynomial::from_vector(power),
+            Polynomial::from_vector(coefficients),
+        );
+        let (mut t0, mut t1) = (Polynomial::zero(), Polynomial::from_constant(T::one()));
+        while !r1.is_zero() && r1.degree() > m {
+            let (q, r) = r0.div_rem(&r1);
+            r0 = std::mem::replace(&mut r1, r);
+            let t = t0 - q * t1.clone();
+            t0 = std::mem::replace(&mut t1, t);
+        }
+        Self::normalized(r1, t1)
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Returns the `[m/n]` Padé approximant of the polynomial, seen as a Taylor expansion around `0`.
+    /// Only the coefficients up to `x^(m+n)` are used.
+    /// In degenerate cases, when no rational function of that shape matches all of them,
+    /// the approximant of the Frobenius definition is returned, which matches fewer coefficients.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::RationalNumber;
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let r = |n, d| RationalNumber::new(n, d);
+    /// // the Taylor expansion of exp(x)
+    /// let exp = Polynomial::from_vector(vec![r(1, 1), r(1, 1), r(1, 2), r(1, 6), r(1, 24)]);
+    /// let pade = exp.pade(2, 2);
+    /// assert_eq!(pade.numerator(), &Polynomial::from_vector(vec![r(1, 1), r(1, 2), r(1, 12)]));
+    /// assert_eq!(pade.denominator(), &Polynomial::from_vector(vec![r(1, 1), r(-1, 2), r(1, 12)]));
+    /// ```
+    pub fn pade(&self, m: usize, n: usize) -> PadeApproximant<T> {
+        let mut coefficients: Vec<T> = self
+            .coefficients()
+            .iter()
+            .take(m + n + 1)
+            .cloned()
+            .collect();
+        coefficients.resize(m + n + 1, T::zero());
+        PadeApproximant::from_coefficients(coefficients, m, n)
+    }
+}
+
+impl<T> PowerSeries<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Returns the `[m/n]` Padé approximant of the series, see [`Polynomial::pade`].
+    /// # Panics
+    /// Panics if the precision of the series is less than `m + n + 1`.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::RationalNumber;
+    /// use rust_polynomials_lib::polynomials::{Polynomial, PowerSeries};
+    /// let r = |n| RationalNumber::new(n, 1);
+    /// // log(1 + x) ~ x (6 + x) / (6 + 4x)
+    /// let log = PowerSeries::new(Polynomial::from_vector(vec![r(1), r(1)]), 4).log();
+    /// let pade = log.pade(2, 1);
+    /// assert_eq!(pade.eval(r(2)), RationalNumber::new(16, 14));
+    /// ```
+    pub fn pade(&self, m: usize, n: usize) -> PadeApproximant<T> {
+        assert!(
+            m + n < self.precision(),
+            "the approximant needs m + n + 1 coefficients"
+        );
+        self.polynomial().pade(m, n)
+    }
+
+    /// Returns the continued fraction expansion of the series, see [`ContinuedFraction`].
+    /// All the known coefficients are used, so the expansion stops once the precision is exhausted,
+    /// or earlier if the series is a rational function of low degree.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::RationalNumber;
+    /// use rust_polynomials_lib::polynomials::{Polynomial, PowerSeries};
+    /// let r = |n| RationalNumber::new(n, 1);
+    /// // 1 / (1 - x) = 1 + x / (1 + x / (-1))
+    /// let geometric = PowerSeries::new(Polynomial::from_vector(vec![r(1), r(-1)]), 8).inverse();
+    /// let fraction = geometric.continued_fraction();
+    /// assert_eq!(fraction.coefficients(), &[r(1), r(1), r(-1)]);
+    /// assert_eq!(fraction.exponents(), &[1, 1]);
+    /// ```
+    pub fn continued_fraction(&self) -> ContinuedFraction<T> {
+        let mut coefficients = Vec::new();
+        let mut exponents = Vec::new();
+        let mut series = self.clone();
+        while series.precision() > 0 {
+            let mut rest = series.polynomial().coefficients().to_vec();
+            coefficients.push(series.coefficient(0));
+            rest[0] = T::zero();
+            let valuation = match rest.iter().position(|c| !c.is_zero()) {
+                Some(it) => it,
+                None => break,
+            };
+            // series = a + x^v g with g(0) != 0, and the expansion continues with 1 / g
+            exponents.push(valuation);
+            series = PowerSeries::new(
+                Polynomial::from_vector(rest[valuation..].to_vec()),
+                series.precision() - valuation,
+            )
+            .inverse();
+        }
+        ContinuedFraction {
+            coefficients,
+            exponents,
+        }
+    }
+}
+
+/// A continued fraction `a_0 + x^(e_1) / (a_1 + x^(e_2) / (a_2 + ... + x^(e_k) / a_k))`
+/// with constants `a_i`, all non-zero except possibly `a_0`.
+/// When all the exponents are `1`, its convergents form the staircase `[0/0], [1/0], [1/1], [2/1], [2/2], ...`
+/// of the Padé table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContinuedFraction<T>
+where
+    T: One + Zero + Clone,
+{
+    coefficients: Vec<T>,
+    exponents: Vec<usize>,
+}
+
+impl<T> ContinuedFraction<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Returns the constants `a_0, ..., a_k`.
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    /// Returns the exponents `e_1, ..., e_k`.
+    pub fn exponents(&self) -> &[usize] {
+        &self.exponents
+    }
+
+    /// Returns the `k`-th convergent, the rational function obtained by stopping the fraction at `a_k`.
+    /// # Panics
+    /// Panics if `k` is not less than the number of coefficients.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::RationalNumber;
+    /// use rust_polynomials_lib::polynomials::{Polynomial, PowerSeries};
+    /// let exp = PowerSeries::new(Polynomial::<RationalNumber>::x(), 8).exp();
+    /// let fraction = exp.continued_fraction();
+    /// assert_eq!(fraction.convergent(4), exp.pade(2, 2));
+    /// ```
+    pub fn convergent(&self, k: usize) -> PadeApproximant<T> {
+        assert!(
+            k < self.coefficients.len(),
+            "convergent beyond the expansion"
+        );
+        let mut numerator = Polynomial::from_constant(self.coefficients[k].clone());
+        let mut denominator = Polynomial::from_constant(T::one());
+        for j in (0..k).rev() {
+            // a_j + x^(e_(j+1)) / (p / q) = (a_j p + x^(e_(j+1)) q) / p
+            let mut power = vec![T::zero(); self.exponents[j]];
+            power.push(T::one());
+            let next = numerator.clone() * self.coefficients[j].clone()
+                + Polynomial::from_vector(power) * denominator;
+            denominator = std::mem::replace(&mut numerator, next);
+        }
+        PadeApproximant::normalized(numerator, denominator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coefficients::RationalNumber;
+
+    fn r(n: i64, d: i64) -> RationalNumber {
+        RationalNumber::new(n, d)
+    }
+
+    fn exp_series(precision: usize) -> PowerSeries<RationalNumber> {
+        PowerSeries::new(Polynomial::x(), precision).exp()
+    }
+
+    #[test]
+    fn test_pade_of_exp() {
+        // [3/3]: (120 + 60x + 12x^2 + x^3) / (120 - 60x + 12x^2 - x^3)
+        let pade = exp_series(7).pade(3, 3);
+        assert_eq!(
+            pade.numerator(),
+            &Polynomial::from_vector(vec![r(1, 1), r(1, 2), r(1, 10), r(1, 120)])
+        );
+        assert_eq!(
+            pade.denominator(),
+            &Polynomial::from_vector(vec![r(1, 1), r(-1, 2), r(1, 10), r(-1, 120)])
+        );
+        // [2/0] is the Taylor polynomial and [0/2] the inverse of the Taylor polynomial of exp(-x)
+        assert_eq!(
+            exp_series(3).pade(2, 0).numerator(),
+            &Polynomial::from_vector(vec![r(1, 1), r(1, 1), r(1, 2)])
+        );
+        assert_eq!(
+            exp_series(3).pade(0, 2).denominator(),
+            &Polynomial::from_vector(vec![r(1, 1), r(-1, 1), r(1, 2)])
+        );
+    }
+
+    #[test]
+    fn test_pade_matches_series() {
+        let series = exp_series(9);
+        for (m, n) in [(1, 3), (4, 2), (0, 5), (3, 5)] {
+            let pade = series.pade(m, n);
+            assert!(pade.numerator().degree() <= m);
+            assert!(pade.denominator().degree() <= n);
+            let product = PowerSeries::new(pade.denominator().clone(), m + n + 1) * series.clone();
+            assert_eq!(
+                product.truncate(m + n + 1).polynomial(),
+                pade.numerator(),
+                "[{m}/{n}]"
+            );
+        }
+    }
+
+    #[test]
+    fn test_degenerate() {
+        // no [1/1] approximant of 1 + x^2 matches three coefficients
+        let p = Polynomial::from_vector(vec![r(1, 1), r(0, 1), r(1, 1)]);
+        let pade = p.pade(1, 1);
+        assert_eq!(pade.numerator(), &Polynomial::from_constant(r(1, 1)));
+        assert_eq!(pade.denominator(), &Polynomial::from_constant(r(1, 1)));
+        // a rational function is its own approximant
+        let f = Polynomial::from_vector(vec![r(1, 1), r(2, 1)]);
+        let g = PowerSeries::new(Polynomial::from_vector(vec![r(1, 1), r(-3, 1)]), 10).inverse();
+        let pade = (PowerSeries::new(f.clone(), 10) * g).pade(4, 4);
+        assert_eq!(pade.numerator(), &f);
+        assert_eq!(
+            pade.denominator(),
+            &Polynomial::from_vector(vec![r(1, 1), r(-3, 1)])
+        );
+    }
+
+    #[test]
+    fn test_pade_f64() {
+        let taylor: Vec<f64> = (0..7)
+            .scan(1.0, |factorial, k| {
+                if k > 0 {
+                    *factorial *= k as f64;
+                }
+                Some(1.0 / *factorial)
+            })
+            .collect();
+        let pade = Polynomial::from_vector(taylor).pade(3, 3);
+        let expected = [1.0, -0.5, 0.1, -1.0 / 120.0];
+        for (c, e) in pade.denominator().coefficients().iter().zip(expected) {
+            assert!((c - e).abs() < 1e-14);
+        }
+        assert!((pade.eval(0.5) - 0.5f64.exp()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_continued_fraction() {
+        // exp(x) = 1 + x / (1 + x / (-2 + x / (-3 + x / (2 + x / (5 + ...)))))
+        let fraction = exp_series(6).continued_fraction();
+        assert_eq!(
+            fraction.coefficients(),
+            &[r(1, 1), r(1, 1), r(-2, 1), r(-3, 1), r(2, 1), r(5, 1)]
+        );
+        assert_eq!(fraction.exponents(), &[1, 1, 1, 1, 1]);
+        let series = exp_series(6);
+        assert_eq!(fraction.convergent(3), series.pade(2, 1));
+        assert_eq!(fraction.convergent(5), series.pade(3, 2));
+        assert_eq!(
+            fraction.convergent(3).to_rational_function(),
+            RationalFunction::new(
+                Polynomial::from_vector(vec![r(6, 1), r(4, 1), r(1, 1)]),
+                Polynomial::from_vector(vec![r(6, 1), r(-2, 1)])
+            )
+        );
+    }
+
+    #[test]
+    fn test_continued_fraction_exponents() {
+        // 1 + x^2 / (1 + x^3 / 2)  =  (2 + 2x^2 + x^3) / (2 + x^3)
+        let f = PowerSeries::new(
+            Polynomial::from_vector(vec![r(2, 1), r(0, 1), r(2, 1), r(1, 1)]),
+            12,
+        ) / PowerSeries::new(
+            Polynomial::from_vector(vec![r(2, 1), r(0, 1), r(0, 1), r(1, 1)]),
+            12,
+        );
+        let fraction = f.continued_fraction();
+        assert_eq!(fraction.coefficients(), &[r(1, 1), r(1, 1), r(2, 1)]);
+        assert_eq!(fraction.exponents(), &[2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the approximant needs m + n + 1 coefficients")]
+    fn test_pade_beyond_precision() {
+        exp_series(4).pade(2, 2);
+    }
+}
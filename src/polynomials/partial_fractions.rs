@@ -0,0 +1,494 @@
+#![forbid(unsafe_code)]
+//! # Partial fractions
+//!
+//! Decomposition of a rational function `P / Q` over a field into a polynomial part and a sum of
+//! proper fractions `A / f^k`, where `f` runs over the square-free factors of `Q`.
+//!
+//! Over `f64` and `Complex<f64>` the denominator is split into linear factors instead,
+//! from its numerically computed roots, and the fractions are given by the residues at the poles.
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use num::{One, Zero};
+
+use super::{Polynomial, RationalFunction};
+use crate::coefficients::Complex;
+
+/// A proper fraction `numerator / factor^multiplicity`, with `deg numerator < deg factor`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartialFraction<T>
+where
+    T: One + Zero + Clone,
+{
+    factor: Polynomial<T>,
+    multiplicity: usize,
+    numerator: Polynomial<T>,
+}
+
+impl<T> PartialFraction<T>
+where
+    T: One + Zero + Clone,
+{
+    /// Returns the monic, square-free factor of the denominator.
+    pub fn factor(&self) -> &Polynomial<T> {
+        &self.factor
+    }
+
+    /// Returns the power of the factor in the denominator.
+    pub fn multiplicity(&self) -> usize {
+        self.multiplicity
+    }
+
+    /// Returns the numerator, whose degree is less than the degree of the factor.
+    pub fn numerator(&self) -> &Polynomial<T> {
+        &self.numerator
+    }
+}
+
+/// The partial fraction decomposition `P / Q = polynomial + sum numerator_i / factor_i^multiplicity_i`.
+///
+/// The factors are the square-free factors of `Q`, which are pairwise coprime but not necessarily irreducible:
+/// no factorization into irreducible polynomials is attempted.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartialFractions<T>
+where
+    T: One + Zero + Clone,
+{
+    polynomial: Polynomial<T>,
+    terms: Vec<PartialFraction<T>>,
+}
+
+impl<T> PartialFractions<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Decomposes `numerator / denominator`, where `denominator` is monic and coprime to `numerator`.
+    pub(crate) fn new(numerator: &Polynomial<T>, denominator: &Polynomial<T>) -> Self {
+        let (polynomial, remainder) = numerator.div_rem(denominator);
+
+        let mut terms = Vec::new();
+        for (factor, multiplicity) in denominator.square_free_decomposition() {
+            let power = (1..multiplicity).fold(factor.clone(), |power, _| power * factor.clone());
+            let cofactor = denominator.div_rem(&power).0;
+
+            // remainder / denominator = sum a_i / power_i with a_i = remainder / cofactor_i mod power_i
+            let inverse = cofactor.extended_gcd(&power).1;
+            let mut numerator = (remainder.clone() * inverse).div_rem(&power).1;
+
+            // the factor-adic expansion a = c_0 + c_1 f + c_2 f^2 + ... gives a / f^k = sum c_j / f^(k - j)
+            for j in 0..multiplicity {
+                let (quotient, digit) = numerator.div_rem(&factor);
+                if !digit.is_zero() {
+                    terms.push(PartialFraction {
+                        factor: factor.clone(),
+                        multiplicity: multiplicity - j,
+                        numerator: digit,
+                    });
+                }
+                numerator = quotient;
+            }
+        }
+
+        Self { polynomial, terms }
+    }
+
+    /// Returns the polynomial part, i.e. the quotient of the division of the numerator by the denominator.
+    pub fn polynomial(&self) -> &Polynomial<T> {
+        &self.polynomial
+    }
+
+    /// Returns the proper fractions.
+    pub fn terms(&self) -> &[PartialFraction<T>] {
+        &self.terms
+    }
+
+    /// Adds the polynomial part and the fractions back together.
+    pub fn to_rational_function(&self) -> RationalFunction<T> {
+        self.terms.iter().fold(
+            RationalFunction::from(self.polynomial.clone()),
+            |sum, term| {
+                let power = (1..term.multiplicity)
+                    .fold(term.factor.clone(), |power, _| power * term.factor.clone());
+                sum + RationalFunction::new(term.numerator.clone(), power)
+            },
+        )
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Returns the partial fraction decomposition of `self / denominator` over the field `T`,
+    /// after the common factors have been cancelled, see [`PartialFractions`].
+    /// # Panics
+    /// Panics if the denominator is the zero polynomial.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::RationalNumber;
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let r = |n| RationalNumber::new(n, 1);
+    /// let numerator = Polynomial::from_vector(vec![r(3), r(1)]); // x + 3
+    /// let denominator = Polynomial::from_vector(vec![r(1), r(2), r(1)]); // (x + 1)^2
+    /// let fractions = numerator.partial_fractions(&denominator);
+    /// // 1 / (x + 1) + 2 / (x + 1)^2
+    /// assert_eq!(fractions.terms()[0].multiplicity(), 2);
+    /// assert_eq!(fractions.terms()[0].numerator(), &Polynomial::from_constant(r(2)));
+    /// assert_eq!(fractions.terms()[1].multiplicity(), 1);
+    /// assert_eq!(fractions.terms()[1].numerator(), &Polynomial::from_constant(r(1)));
+    /// ```
+    pub fn partial_fractions(&self, denominator: &Self) -> PartialFractions<T> {
+        RationalFunction::new(self.clone(), denominator.clone()).partial_fractions()
+    }
+}
+
+/// A pole `root` of multiplicity `m` of a rational function, together with the coefficients `c_1, ..., c_m`
+/// of its principal part `c_1 / (x - root) + ... + c_m / (x - root)^m`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pole {
+    root: Complex<f64>,
+    coefficients: Vec<Complex<f64>>,
+}
+
+impl Pole {
+    /// Returns the location of the pole.
+    pub fn root(&self) -> Complex<f64> {
+        self.root
+    }
+
+    /// Returns the multiplicity of the pole.
+    pub fn multiplicity(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    /// Returns the coefficients `c_1, ..., c_m` of the principal part, `c_j` being the one of `1 / (x - root)^j`.
+    pub fn coefficients(&self) -> &[Complex<f64>] {
+        &self.coefficients
+    }
+
+    /// Returns the residue `c_1` of the pole.
+    pub fn residue(&self) -> Complex<f64> {
+        self.coefficients[0]
+    }
+}
+
+/// The partial fraction decomposition `P / Q = polynomial + sum of the principal parts of the poles`
+/// of a rational function with floating-point coefficients, see [`Polynomial::linear_partial_fractions`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinearPartialFractions {
+    polynomial: Polynomial<Complex<f64>>,
+    poles: Vec<Pole>,
+}
+
+impl LinearPartialFractions {
+    /// Returns the polynomial part.
+    pub fn polynomial(&self) -> &Polynomial<Complex<f64>> {
+        &self.polynomial
+    }
+
+    /// Returns the poles with their principal parts.
+    pub fn poles(&self) -> &[Pole] {
+        &self.poles
+    }
+
+    /// Evaluates the decomposition at `x`, which must not be a pole.
+    pub fn eval(&self, x: Complex<f64>) -> Complex<f64> {
+        let mut sum = self.polynomial.eval(x);
+        for pole in &self.poles {
+            let inverse = (x - pole.root).inv();
+            let mut power = inverse;
+            for &c in &pole.coefficients {
+                sum += c * power;
+                power *= inverse;
+            }
+        }
+        sum
+    }
+}
+
+/// Returns the first `n` Taylor coefficients of `p` around `center`, i.e. of `p(center + t)`,
+/// computed with repeated synthetic division.
+fn taylor_coefficients(
+    p: &Polynomial<Complex<f64>>,
+    center: Complex<f64>,
+    n: usize,
+) -> Vec<Complex<f64>> {
+    let mut coefficients = p.coefficients().to_vec();
+    let mut result = Vec::with_capacity(n);
+    for _ in 0..n {
+        if coefficients.is_empty() {
+            result.push(Complex::zero());
+            continue;
+        }
+        // divides by (x - center), the remainder is the next Taylor coefficient
+        for it in (0..coefficients.len() - 1).rev() {
+            let carry = coefficients[it + 1] * center;
+            coefficients[it] += carry;
+        }
+        result.push(coefficients.remove(0));
+    }
+    result
+}
+
+/// Returns the product of two truncated series, keeping `n` coefficients.
+fn mul_series(a: &[Complex<f64>], b: &[Complex<f64>], n: usize) -> Vec<Complex<f64>> {
+    let mut product = vec![Complex::zero(); n];
+    for (i, &a) in a.iter().enumerate().take(n) {
+        for (j, &b) in b.iter().enumerate().take(n - i) {
+            product[i + j] += a * b;
+        }
+    }
+    product
+}
+
+impl Polynomial<Complex<f64>> {
+    /// Returns the partial fraction decomposition of `self / denominator` into linear factors.
+    /// The poles are the roots of the denominator found by [`Polynomial::roots`]; the roots closer to each other
+    /// than `tolerance` are merged into a single pole of higher multiplicity.
+    /// The numerator and the denominator are expected to be coprime, a cancelled pole shows up with vanishing coefficients.
+    /// # Panics
+    /// Panics if the denominator is the zero polynomial.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::Complex;
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let numerator = Polynomial::from_constant(1.0).to_complex();
+    /// let denominator = Polynomial::from_vector(vec![1.0, 0.0, 1.0]).to_complex(); // x^2 + 1
+    /// let fractions = numerator.linear_partial_fractions(&denominator, 1e-6);
+    /// // 1 / (x^2 + 1) = (i / 2) / (x + i) - (i / 2) / (x - i), the residue at a root r is 1 / (2r)
+    /// for pole in fractions.poles() {
+    ///     assert!((pole.residue() - Complex::new(0.5, 0.0) / pole.root()).norm() < 1e-12);
+    /// }
+    /// ```
+    pub fn linear_partial_fractions(
+        &self,
+        denominator: &Self,
+        tolerance: f64,
+    ) -> LinearPartialFractions {
+        let (polynomial, remainder) = self.div_rem(denominator);
+        let leading = denominator.coefficients()[denominator.degree()];
+
+        // the roots within the tolerance of each other form a cluster, represented by their mean
+        let mut clusters: Vec<Vec<Complex<f64>>> = Vec::new();
+        for root in denominator.roots() {
+            match clusters
+                .iter_mut()
+                .find(|cluster| (cluster[0] - root).norm() <= tolerance)
+            {
+                Some(cluster) => cluster.push(root),
+                None => clusters.push(vec![root]),
+            }
+        }
+        let centers: Vec<(Complex<f64>, usize)> = clusters
+            .iter()
+            .map(|cluster| {
+                let sum: Complex<f64> = cluster.iter().sum();
+                (sum / cluster.len() as f64, cluster.len())
+            })
+            .collect();
+
+        let poles = centers
+            .iter()
+            .enumerate()
+            .map(|(k, &(root, multiplicity))| {
+                // g(t) = R(root + t) / (leading * prod_{other poles} (t + root - other)^m), so that
+                // R(x) / Q(x) = g(x - root) / (x - root)^multiplicity
+                let mut g = taylor_coefficients(&remainder, root, multiplicity);
+                for (j, &(other, other_multiplicity)) in centers.iter().enumerate() {
+                    if j == k {
+                        continue;
+                    }
+                    // 1 / (t + a) = sum (-1)^i t^i / a^(i + 1)
+                    let a = root - other;
+                    let mut inverse = Vec::with_capacity(multiplicity);
+                    let mut term = a.inv();
+                    for _ in 0..multiplicity {
+                        inverse.push(term);
+                        term = -term / a;
+                    }
+                    for _ in 0..other_multiplicity {
+                        g = mul_series(&g, &inverse, multiplicity);
+                    }
+                }
+                let coefficients = (1..=multiplicity)
+                    .map(|j| g[multiplicity - j] / leading)
+                    .collect();
+                Pole { root, coefficients }
+            })
+            .collect();
+
+        LinearPartialFractions { polynomial, poles }
+    }
+}
+
+impl Polynomial<f64> {
+    /// Returns the partial fraction decomposition of `self / denominator` into complex linear factors,
+    /// see [`Polynomial::linear_partial_fractions`] for complex polynomials.
+    /// The poles which are not real come in complex conjugate pairs.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let numerator = Polynomial::from_vector(vec![1.0, 1.0]); // x + 1
+    /// let denominator = Polynomial::from_vector(vec![0.0, -1.0, 1.0]); // x (x - 1)
+    /// let fractions = numerator.linear_partial_fractions(&denominator, 1e-6);
+    /// // (x + 1) / (x (x - 1)) = -1 / x + 2 / (x - 1)
+    /// for pole in fractions.poles() {
+    ///     let expected = if pole.root().norm() < 0.5 { -1.0 } else { 2.0 };
+    ///     assert!((pole.residue().re - expected).abs() < 1e-14);
+    /// }
+    /// ```
+    pub fn linear_partial_fractions(
+        &self,
+        denominator: &Self,
+        tolerance: f64,
+    ) -> LinearPartialFractions {
+        self.to_complex()
+            .linear_partial_fractions(&denominator.to_complex(), tolerance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coefficients::RationalNumber;
+
+    fn r(n: i64, d: i64) -> RationalNumber {
+        RationalNumber::new(n, d)
+    }
+
+    fn poly(coefficients: &[i64]) -> Polynomial<RationalNumber> {
+        Polynomial::from_vector(coefficients.iter().map(|&c| r(c, 1)).collect())
+    }
+
+    #[test]
+    fn test_repeated_factor() {
+        // (x^3 + 1) / ((x - 1)^2 (x + 2)) = 1 + A / (x + 2) + B / (x - 1) + C / (x - 1)^2
+        let f = RationalFunction::new(poly(&[1, 0, 0, 1]), poly(&[2, -3, 0, 1]));
+        let fractions = f.partial_fractions();
+        assert_eq!(fractions.polynomial(), &poly(&[1]));
+
+        let terms = fractions.terms();
+        assert_eq!(terms.len(), 3);
+        assert_eq!(terms[0].factor(), &poly(&[2, 1]));
+        assert_eq!(terms[0].multiplicity(), 1);
+        assert_eq!(terms[0].numerator(), &Polynomial::from_constant(r(-7, 9)));
+        assert_eq!(terms[1].factor(), &poly(&[-1, 1]));
+        assert_eq!(terms[1].multiplicity(), 2);
+        assert_eq!(terms[1].numerator(), &Polynomial::from_constant(r(2, 3)));
+        assert_eq!(terms[2].factor(), &poly(&[-1, 1]));
+        assert_eq!(terms[2].multiplicity(), 1);
+        assert_eq!(terms[2].numerator(), &Polynomial::from_constant(r(7, 9)));
+
+        assert_eq!(fractions.to_rational_function(), f);
+    }
+
+    #[test]
+    fn test_irreducible_quadratic() {
+        // x / ((x^2 + 1)^2 (x - 3))
+        let denominator = poly(&[1, 0, 1]) * poly(&[1, 0, 1]) * poly(&[-3, 1]);
+        let f = RationalFunction::new(poly(&[0, 1]), denominator);
+        let fractions = f.partial_fractions();
+        assert!(fractions.polynomial().is_zero());
+        // 3 / (100 (x - 3)) - (3x - 1) / (10 (x^2 + 1)^2) - (3x + 9) / (100 (x^2 + 1))
+        let terms = fractions.terms();
+        assert_eq!(terms.len(), 3);
+        assert_eq!(terms[0].factor(), &poly(&[-3, 1]));
+        assert_eq!(terms[0].multiplicity(), 1);
+        assert_eq!(terms[0].numerator(), &Polynomial::from_constant(r(3, 100)));
+        assert_eq!(terms[1].factor(), &poly(&[1, 0, 1]));
+        assert_eq!(terms[1].multiplicity(), 2);
+        assert_eq!(
+            terms[1].numerator(),
+            &Polynomial::from_vector(vec![r(1, 10), r(-3, 10)])
+        );
+        assert_eq!(terms[2].factor(), &poly(&[1, 0, 1]));
+        assert_eq!(terms[2].multiplicity(), 1);
+        assert_eq!(
+            terms[2].numerator(),
+            &Polynomial::from_vector(vec![r(-9, 100), r(-3, 100)])
+        );
+
+        assert_eq!(fractions.to_rational_function(), f);
+    }
+
+    #[test]
+    fn test_polynomial() {
+        let f = RationalFunction::from(poly(&[1, 2, 3]));
+        let fractions = f.partial_fractions();
+        assert_eq!(fractions.polynomial(), &poly(&[1, 2, 3]));
+        assert!(fractions.terms().is_empty());
+    }
+
+    #[test]
+    fn test_partial_fractions_of_polynomials() {
+        // the common factor x - 1 is cancelled first
+        let numerator = poly(&[-1, 0, 1]);
+        let denominator = poly(&[1, -2, 1]) * poly(&[2, 1]);
+        let fractions = numerator.partial_fractions(&denominator);
+        assert_eq!(
+            fractions.to_rational_function(),
+            RationalFunction::new(poly(&[1, 1]), poly(&[-2, 1, 1]))
+        );
+    }
+
+    #[test]
+    fn test_taylor_coefficients() {
+        let p = Polynomial::from_vector(vec![1.0, 2.0, 3.0]).to_complex();
+        // p(1 + t) = 6 + 8t + 3t^2
+        let taylor = taylor_coefficients(&p, Complex::new(1.0, 0.0), 4);
+        assert_eq!(
+            taylor,
+            vec![
+                Complex::new(6.0, 0.0),
+                Complex::new(8.0, 0.0),
+                Complex::new(3.0, 0.0),
+                Complex::new(0.0, 0.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_linear_partial_fractions_simple_poles() {
+        // (x^3 + 2) / ((x - 1)(x + 2)(x^2 + 1))
+        let numerator = Polynomial::from_vector(vec![2.0, 0.0, 0.0, 1.0]);
+        let denominator = Polynomial::from_vector(vec![-2.0, 1.0, -1.0, 1.0, 1.0]);
+        let fractions = numerator.linear_partial_fractions(&denominator, 1e-6);
+        assert_eq!(fractions.poles().len(), 4);
+        assert!(fractions.polynomial().is_zero());
+        for x in [
+            Complex::new(0.3, 0.1),
+            Complex::new(-4.0, 2.0),
+            Complex::new(5.0, 0.0),
+        ] {
+            let expected = numerator.to_complex().eval(x) / denominator.to_complex().eval(x);
+            assert!((fractions.eval(x) - expected).norm() < 1e-12);
+        }
+        // the residue at 1 is 3 / (3 * 2)
+        let pole = fractions
+            .poles()
+            .iter()
+            .find(|pole| (pole.root() - Complex::new(1.0, 0.0)).norm() < 1e-9)
+            .unwrap();
+        assert!((pole.residue() - Complex::new(0.5, 0.0)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_linear_partial_fractions_multiple_pole() {
+        // (x^3 + 1) / ((x - 1)^2 (x + 2)), see test_repeated_factor
+        let numerator = Polynomial::from_vector(vec![1.0, 0.0, 0.0, 1.0]);
+        let denominator = Polynomial::from_vector(vec![2.0, -3.0, 0.0, 1.0]);
+        let fractions = numerator.linear_partial_fractions(&denominator, 1e-4);
+        assert!(
+            (fractions.polynomial().eval(Complex::<f64>::zero()) - Complex::one()).norm() < 1e-12
+        );
+
+        let double = fractions
+            .poles()
+            .iter()
+            .find(|pole| pole.multiplicity() == 2)
+            .unwrap();
+        assert!((double.root() - Complex::new(1.0, 0.0)).norm() < 1e-7);
+        assert!((double.coefficients()[0] - Complex::new(7.0 / 9.0, 0.0)).norm() < 1e-6);
+        assert!((double.coefficients()[1] - Complex::new(2.0 / 3.0, 0.0)).norm() < 1e-6);
+    }
+}
@@ -1,4 +1,6 @@
 #![forbid(unsafe_code)]
+// the index loops of the original matrix and polynomial code already failed this lint
+#![allow(clippy::needless_range_loop)]
 //! # Polynomials
 
 use std::ops::Mul;
@@ -79,6 +81,29 @@ where
             self.coefficients.pop();
         }
     }
+
+    /// Returns the coefficients of the polynomial, starting from the constant term.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let p = Polynomial::from_vector(vec![1, 2, 3]); // 1 + 2x + 3x^2
+    /// assert_eq!(p.coefficients(), &[1, 2, 3]);
+    /// ```
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    /// Returns the degree of the polynomial.
+    /// The zero polynomial is considered to have degree `0`.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let p = Polynomial::from_vector(vec![1, 2, 3]); // 1 + 2x + 3x^2
+    /// assert_eq!(p.degree(), 2);
+    /// ```
+    pub fn degree(&self) -> usize {
+        self.coefficients.len().saturating_sub(1)
+    }
 }
 
 impl<T> Polynomial<T>
@@ -107,6 +132,193 @@ where
     }
 }
 
+impl<T> Polynomial<T>
+where
+    T: One + Zero + Clone + std::ops::Add<Output = T> + Mul<Output = T>,
+{
+    /// Returns the formal derivative of the polynomial.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let p = Polynomial::from_vector(vec![1, 2, 3]); // 1 + 2x + 3x^2
+    /// assert_eq!(p.derivative(), Polynomial::from_vector(vec![2, 6])); // 2 + 6x
+    /// ```
+    pub fn derivative(&self) -> Self {
+        let mut coefficients = Vec::with_capacity(self.coefficients.len());
+        let mut factor = T::zero();
+        for coeff in self.coefficients.iter().skip(1) {
+            factor = factor + T::one();
+            coefficients.push(coeff.clone() * factor.clone());
+        }
+        if coefficients.is_empty() {
+            return Self::zero();
+        }
+        Self::from_vector(coefficients)
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: One
+        + Zero
+        + Clone
+        + std::ops::Add<Output = T>
+        + std::ops::Sub<Output = T>
+        + Mul<Output = T>
+        + std::ops::Div<Output = T>,
+{
+    /// Divides the polynomial by `divisor`, returning the quotient and the remainder.
+    /// The degree of the remainder is less than the degree of the divisor, `T` is expected to be a field.
+    /// # Panics
+    /// Panics if `divisor` is the zero polynomial.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let p = Polynomial::from_vector(vec![1.0, 0.0, 1.0]); // 1 + x^2
+    /// let (q, r) = p.div_rem(&Polynomial::from_vector(vec![-1.0, 1.0])); // x - 1
+    /// assert_eq!(q, Polynomial::from_vector(vec![1.0, 1.0])); // 1 + x
+    /// assert_eq!(r, Polynomial::from_constant(2.0));
+    /// ```
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(!divisor.is_zero(), "division by zero polynomial");
+        let divisor_degree = divisor.degree();
+        if self.coefficients.len() <= divisor_degree {
+            return (Self::zero(), self.clone());
+        }
+
+        let leading = divisor.coefficients[divisor_degree].clone();
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = vec![T::zero(); remainder.len() - divisor_degree];
+        for deg in (0..quotient.len()).rev() {
+            let coeff = remainder[deg + divisor_degree].clone() / leading.clone();
+            for (it, divisor_coeff) in divisor.coefficients.iter().enumerate() {
+                remainder[deg + it] =
+                    remainder[deg + it].clone() - coeff.clone() * divisor_coeff.clone();
+            }
+            quotient[deg] = coeff;
+        }
+        remainder.truncate(divisor_degree.max(1));
+        if divisor_degree == 0 {
+            remainder[0] = T::zero();
+        }
+
+        (Self::from_vector(quotient), Self::from_vector(remainder))
+    }
+
+    /// Returns the polynomial divided by its leading coefficient.
+    /// The zero polynomial is returned unchanged.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let p = Polynomial::from_vector(vec![1.0, 2.0, 4.0]);
+    /// assert_eq!(p.monic(), Polynomial::from_vector(vec![0.25, 0.5, 1.0]));
+    /// ```
+    pub fn monic(&self) -> Self {
+        if self.is_zero() {
+            return self.clone();
+        }
+        let leading = self.coefficients[self.degree()].clone();
+        Self::from_vector(
+            self.coefficients
+                .iter()
+                .map(|c| c.clone() / leading.clone())
+                .collect(),
+        )
+    }
+
+    /// Returns the monic greatest common divisor of two polynomials, computed with the Euclidean algorithm.
+    /// The gcd of two zero polynomials is zero.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let p = Polynomial::from_vector(vec![-1.0, 0.0, 1.0]); // (x - 1)(x + 1)
+    /// let q = Polynomial::from_vector(vec![2.0, -2.0]); // -2(x - 1)
+    /// assert_eq!(p.gcd(&q), Polynomial::from_vector(vec![-1.0, 1.0])); // x - 1
+    /// ```
+    pub fn gcd(&self, other: &Self) -> Self {
+        let mut a = self.clone();
+        let mut b = other.clone();
+        while !b.is_zero() {
+            let r = a.div_rem(&b).1;
+            a = b;
+            b = r;
+        }
+        a.monic()
+    }
+
+    /// Returns the monic greatest common divisor `g` of two polynomials together with the Bézout
+    /// coefficients `s` and `t` such that `s * self + t * other = g`, computed with the extended Euclidean algorithm.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::RationalNumber;
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let r = |n| RationalNumber::new(n, 1);
+    /// let p = Polynomial::from_vector(vec![r(-1), r(0), r(1)]); // (x - 1)(x + 1)
+    /// let q = Polynomial::from_vector(vec![r(2), r(1)]); // x + 2
+    /// let (g, s, t) = p.extended_gcd(&q);
+    /// assert_eq!(g, Polynomial::from_constant(r(1)));
+    /// assert_eq!(s * p + t * q, g);
+    /// ```
+    pub fn extended_gcd(&self, other: &Self) -> (Self, Self, Self) {
+        let (mut r0, mut r1) = (self.clone(), other.clone());
+        let (mut s0, mut s1) = (Self::from_constant(T::one()), Self::zero());
+        let (mut t0, mut t1) = (Self::zero(), Self::from_constant(T::one()));
+        while !r1.is_zero() {
+            let (q, r) = r0.div_rem(&r1);
+            r0 = std::mem::replace(&mut r1, r);
+            let s = s0 - q.clone() * s1.clone();
+            s0 = std::mem::replace(&mut s1, s);
+            let t = t0 - q * t1.clone();
+            t0 = std::mem::replace(&mut t1, t);
+        }
+        if r0.is_zero() {
+            return (r0, s0, t0);
+        }
+        let inverse = T::one() / r0.coefficients[r0.degree()].clone();
+        (r0 * inverse.clone(), s0 * inverse.clone(), t0 * inverse)
+    }
+
+    /// Returns the square-free decomposition of the polynomial, computed with Yun's algorithm:
+    /// pairwise coprime, square-free, monic polynomials `s_i` with their multiplicities `i`,
+    /// such that the polynomial is `c * s_1 * s_2^2 * ...` for a constant `c`.
+    /// Only the factors which are not constant are returned, in the order of increasing multiplicity.
+    /// `T` is expected to be a field of characteristic zero with exact arithmetic.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::RationalNumber;
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let r = |n| RationalNumber::new(n, 1);
+    /// let a = Polynomial::from_vector(vec![r(-1), r(1)]); // x - 1
+    /// let b = Polynomial::from_vector(vec![r(2), r(1)]); // x + 2
+    /// let p = a.clone() * b.clone() * b.clone() * r(3);
+    /// assert_eq!(p.square_free_decomposition(), vec![(a, 1), (b, 2)]);
+    /// ```
+    pub fn square_free_decomposition(&self) -> Vec<(Self, usize)> {
+        let mut factors = Vec::new();
+        if self.degree() == 0 {
+            return factors;
+        }
+
+        let derivative = self.derivative();
+        let common = self.gcd(&derivative);
+        let mut b = self.div_rem(&common).0;
+        let c = derivative.div_rem(&common).0;
+        let mut d = c - b.derivative();
+        let mut multiplicity = 1;
+        while b.degree() > 0 {
+            let a = b.gcd(&d);
+            b = b.div_rem(&a).0;
+            let c = d.div_rem(&a).0;
+            d = c - b.derivative();
+            if a.degree() > 0 {
+                factors.push((a, multiplicity));
+            }
+            multiplicity += 1;
+        }
+        factors
+    }
+}
+
 impl<T> Zero for Polynomial<T>
 where
     T: Zero + Clone + One,
@@ -242,6 +454,42 @@ where
     }
 }
 
+/// Quotient of the polynomial division, see [`Polynomial::div_rem`].
+impl<T> std::ops::Div for Polynomial<T>
+where
+    T: One
+        + Zero
+        + Clone
+        + std::ops::Add<Output = T>
+        + std::ops::Sub<Output = T>
+        + Mul<Output = T>
+        + std::ops::Div<Output = T>,
+{
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self.div_rem(&rhs).0
+    }
+}
+
+/// Remainder of the polynomial division, see [`Polynomial::div_rem`].
+impl<T> std::ops::Rem for Polynomial<T>
+where
+    T: One
+        + Zero
+        + Clone
+        + std::ops::Add<Output = T>
+        + std::ops::Sub<Output = T>
+        + Mul<Output = T>
+        + std::ops::Div<Output = T>,
+{
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        self.div_rem(&rhs).1
+    }
+}
+
 /// Exponentiation of a polynomial.
 impl<T> std::ops::BitXor<usize> for Polynomial<T>
 where
@@ -271,6 +519,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::coefficients::RationalNumber;
 
     #[test]
     fn test_i64_coeffs() {
@@ -293,6 +542,88 @@ mod tests {
         assert_eq!(res, 17);
     }
 
+    #[test]
+    fn test_derivative() {
+        let p = Polynomial::from_vector(vec![5, 2, 0, 1]); // 5 + 2x + x^3
+        assert_eq!(p.derivative(), Polynomial::from_vector(vec![2, 0, 3]));
+        assert_eq!(
+            p.derivative().derivative().derivative(),
+            Polynomial::from_constant(6)
+        );
+        assert!(Polynomial::from_constant(5).derivative().is_zero());
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let p = Polynomial::from_vector(vec![5.0, 2.0, 0.0, 1.0]); // 5 + 2x + x^3
+        let d = Polynomial::from_vector(vec![1.0, 0.0, 2.0]); // 1 + 2x^2
+        let (q, r) = p.div_rem(&d);
+        assert_eq!(q, Polynomial::from_vector(vec![0.0, 0.5]));
+        assert_eq!(r, Polynomial::from_vector(vec![5.0, 1.5]));
+        assert_eq!(q.clone() * d.clone() + r.clone(), p);
+        assert_eq!(p.clone() / d.clone(), q);
+        assert_eq!(p.clone() % d.clone(), r);
+
+        let (q, r) = d.div_rem(&p);
+        assert!(q.is_zero());
+        assert_eq!(r, d);
+
+        let (q, r) = p.div_rem(&Polynomial::from_constant(2.0));
+        assert_eq!(q, Polynomial::from_vector(vec![2.5, 1.0, 0.0, 0.5]));
+        assert!(r.is_zero());
+    }
+
+    #[test]
+    fn test_gcd() {
+        let a = Polynomial::from_vector(vec![1.0, 1.0]); // x + 1
+        let b = Polynomial::from_vector(vec![-2.0, 1.0]); // x - 2
+        let c = Polynomial::from_vector(vec![3.0, 0.0, 1.0]); // x^2 + 3
+        let p = a.clone() * b.clone() * 2.0;
+        let q = a.clone() * c.clone() * -3.0;
+        assert_eq!(p.gcd(&q), a);
+        assert_eq!(b.gcd(&c), Polynomial::from_constant(1.0));
+        assert_eq!(p.gcd(&Polynomial::zero()), p.monic());
+        assert!(Polynomial::<f64>::zero().gcd(&Polynomial::zero()).is_zero());
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        let r = |n| RationalNumber::new(n, 1);
+        let a = Polynomial::from_vector(vec![r(1), r(1)]); // x + 1
+        let p = a.clone() * Polynomial::from_vector(vec![r(-2), r(1)]) * r(2);
+        let q = a.clone() * Polynomial::from_vector(vec![r(3), r(0), r(1)]);
+        let (g, s, t) = p.extended_gcd(&q);
+        assert_eq!(g, a);
+        assert_eq!(s * p.clone() + t * q, g);
+        let (g, s, t) = p.extended_gcd(&Polynomial::zero());
+        assert_eq!(g, p.monic());
+        assert_eq!(s * p, g);
+        assert!(t.is_zero());
+    }
+
+    #[test]
+    fn test_square_free_decomposition() {
+        let r = |n| RationalNumber::new(n, 1);
+        let a = Polynomial::from_vector(vec![r(1), r(0), r(1)]); // x^2 + 1
+        let b = Polynomial::from_vector(vec![r(-3), r(1)]); // x - 3
+        let c = Polynomial::x();
+        let p = a.clone() * (b.clone() ^ 3) * (c.clone() ^ 3) * r(-2);
+        assert_eq!(
+            p.square_free_decomposition(),
+            vec![(a.clone(), 1), (b * c, 3)]
+        );
+        assert_eq!(a.square_free_decomposition(), vec![(a, 1)]);
+        assert!(Polynomial::from_constant(r(5))
+            .square_free_decomposition()
+            .is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero polynomial")]
+    fn test_div_by_zero_polynomial() {
+        let _ = Polynomial::from_vector(vec![1.0, 2.0]).div_rem(&Polynomial::zero());
+    }
+
     #[test]
     fn test_pow() {
         let p = Polynomial::from_vector(vec![1, 2, 3]);
@@ -0,0 +1,616 @@
+#![forbid(unsafe_code)]
+//! # Polynomial matrices
+//!
+//! Normal forms of matrices over `K[x]`, where the coefficients `K` form a field with exact arithmetic,
+//! such as `RationalNumber`.
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use num::{One, Zero};
+
+use super::Polynomial;
+use crate::coefficients::{Matrix, RationalNumber};
+
+/// The Smith normal form `S = U A V` of a polynomial matrix `A`, see [`Matrix::smith_normal_form`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SmithNormalForm<T>
+where
+    T: One + Zero + Clone,
+{
+    left: Matrix<Polynomial<T>>,
+    normal_form: Matrix<Polynomial<T>>,
+    right: Matrix<Polynomial<T>>,
+}
+
+/// The Hermite normal form `H = U A` of a polynomial matrix `A`, see [`Matrix::hermite_normal_form`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct HermiteNormalForm<T>
+where
+    T: One + Zero + Clone,
+{
+    transform: Matrix<Polynomial<T>>,
+    normal_form: Matrix<Polynomial<T>>,
+}
+
+impl<T> SmithNormalForm<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Returns the unimodular matrix `U` of the row operations.
+    pub fn left(&self) -> &Matrix<Polynomial<T>> {
+        &self.left
+    }
+
+    /// Returns the diagonal matrix `S`.
+    pub fn normal_form(&self) -> &Matrix<Polynomial<T>> {
+        &self.normal_form
+    }
+
+    /// Returns the unimodular matrix `V` of the column operations.
+    pub fn right(&self) -> &Matrix<Polynomial<T>> {
+        &self.right
+    }
+
+    /// Returns the invariant factors, i.e. the non-zero diagonal elements of `S`.
+    /// They are monic and each of them divides the next one.
+    pub fn invariant_factors(&self) -> Vec<Polynomial<T>> {
+        let (rows, cols) = self.normal_form.shape().unwrap_or((0, 0));
+        (0..rows.min(cols))
+            .map(|it| self.normal_form[(it, it)].clone())
+            .filter(|it| !it.is_zero())
+            .collect()
+    }
+}
+
+impl<T> HermiteNormalForm<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Returns the unimodular matrix `U` of the row operations.
+    pub fn transform(&self) -> &Matrix<Polynomial<T>> {
+        &self.transform
+    }
+
+    /// Returns the upper echelon matrix `H`.
+    pub fn normal_form(&self) -> &Matrix<Polynomial<T>> {
+        &self.normal_form
+    }
+}
+
+/// `row_target -= factor * row_source`
+fn sub_row<T>(m: &mut Matrix<Polynomial<T>>, target: usize, source: usize, factor: &Polynomial<T>)
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    for col in 0..m.cols() {
+        m[(target, col)] = m[(target, col)].clone() - factor.clone() * m[(source, col)].clone();
+    }
+}
+
+/// `col_target -= factor * col_source`
+fn sub_col<T>(m: &mut Matrix<Polynomial<T>>, target: usize, source: usize, factor: &Polynomial<T>)
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    for row in 0..m.rows() {
+        m[(row, target)] = m[(row, target)].clone() - m[(row, source)].clone() * factor.clone();
+    }
+}
+
+/// `row *= factor`
+fn scale_row<T>(m: &mut Matrix<Polynomial<T>>, row: usize, factor: T)
+where
+    T: Clone + Zero + One + Add<Output = T> + Mul<Output = T>,
+{
+    for col in 0..m.cols() {
+        m[(row, col)] = m[(row, col)].clone() * factor.clone();
+    }
+}
+
+impl<T> Matrix<Polynomial<T>>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Returns the Smith normal form `S = U A V` of the matrix:
+    /// `S` is diagonal with monic diagonal elements `d_1 | d_2 | ...` followed by zeros,
+    /// and `U`, `V` are unimodular, i.e. invertible over `K[x]`.
+    /// # Panics
+    /// Panics for a scalar matrix of unspecified size.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::{Matrix, RationalNumber};
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// use num::Zero;
+    /// let r = |n| RationalNumber::new(n, 1);
+    /// let x = Polynomial::x();
+    /// let one = Polynomial::from_constant(r(1));
+    /// // x I - A for a Jordan block A of size 2
+    /// let m = Matrix::from_rows(vec![
+    ///     vec![x.clone() - r(2), Polynomial::from_constant(r(-1))],
+    ///     vec![Polynomial::zero(), x.clone() - r(2)],
+    /// ]);
+    /// let snf = m.smith_normal_form();
+    /// assert_eq!(snf.invariant_factors(), vec![one, (x - r(2)) ^ 2]);
+    /// assert_eq!(snf.left().clone() * m * snf.right().clone(), snf.normal_form().clone());
+    /// ```
+    pub fn smith_normal_form(&self) -> SmithNormalForm<T> {
+        let (rows, cols) = (self.rows(), self.cols());
+        let mut s = self.clone();
+        let mut left = Matrix::identity(rows);
+        let mut right = Matrix::identity(cols);
+
+        for t in 0..rows.min(cols) {
+            loop {
+                // the non-zero element of the smallest degree becomes the pivot
+                let pivot = (t..rows)
+                    .flat_map(|row| (t..cols).map(move |col| (row, col)))
+                    .filter(|&(row, col)| !s[(row, col)].is_zero())
+                    .min_by_key(|&(row, col)| s[(row, col)].degree());
+                let Some((pivot_row, pivot_col)) = pivot else {
+                    return SmithNormalForm {
+                        left,
+                        normal_form: s,
+                        right,
+                    };
+                };
+                s.swap_rows(t, pivot_row);
+                left.swap_rows(t, pivot_row);
+                s.swap_cols(t, pivot_col);
+                right.swap_cols(t, pivot_col);
+
+                let mut reduced = true;
+                for row in t + 1..rows {
+                    let (quotient, remainder) = s[(row, t)].div_rem(&s[(t, t)]);
+                    sub_row(&mut s, row, t, &quotient);
+                    sub_row(&mut left, row, t, &quotient);
+                    reduced &= remainder.is_zero();
+                }
+                for col in t + 1..cols {
+                    let (quotient, remainder) = s[(t, col)].div_rem(&s[(t, t)]);
+                    sub_col(&mut s, col, t, &quotient);
+                    sub_col(&mut right, col, t, &quotient);
+                    reduced &= remainder.is_zero();
+                }
+                if !reduced {
+                    continue;
+                }
+
+                // the pivot has to divide every remaining element
+                let not_divisible = (t + 1..rows).find(|&row| {
+                    (t + 1..cols).any(|col| !s[(row, col)].div_rem(&s[(t, t)]).1.is_zero())
+                });
+                match not_divisible {
+                    Some(row) => {
+                        let minus_one = Polynomial::from_constant(T::zero() - T::one());
+                        sub_row(&mut s, t, row, &minus_one);
+                        sub_row(&mut left, t, row, &minus_one);
+                    }
+                    None => break,
+                }
+            }
+
+            let leading = s[(t, t)].coefficients()[s[(t, t)].degree()].clone();
+            scale_row(&mut s, t, T::one() / leading.clone());
+            scale_row(&mut left, t, T::one() / leading);
+        }
+
+        SmithNormalForm {
+            left,
+            normal_form: s,
+            right,
+        }
+    }
+
+    /// Returns the Hermite normal form `H = U A` of the matrix:
+    /// `H` is in row echelon form, its pivots are monic and the elements above every pivot
+    /// have a smaller degree than the pivot, and `U` is unimodular.
+    /// # Panics
+    /// Panics for a scalar matrix of unspecified size.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::{Matrix, RationalNumber};
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// use num::Zero;
+    /// let x = Polynomial::<RationalNumber>::x();
+    /// let m = Matrix::from_rows(vec![vec![x.clone(), x.clone() * x.clone()], vec![x.clone(), x.clone()]]);
+    /// let hnf = m.hermite_normal_form();
+    /// assert_eq!(hnf.transform().clone() * m, hnf.normal_form().clone());
+    /// assert!(hnf.normal_form()[(1, 0)].is_zero());
+    /// ```
+    pub fn hermite_normal_form(&self) -> HermiteNormalForm<T> {
+        let (rows, cols) = (self.rows(), self.cols());
+        let mut h = self.clone();
+        let mut transform = Matrix::identity(rows);
+
+        let mut pivot_row = 0;
+        for col in 0..cols {
+            if pivot_row == rows {
+                break;
+            }
+            loop {
+                let pivot = (pivot_row..rows)
+                    .filter(|&row| !h[(row, col)].is_zero())
+                    .min_by_key(|&row| h[(row, col)].degree());
+                let Some(pivot) = pivot else {
+                    break;
+                };
+                h.swap_rows(pivot_row, pivot);
+                transform.swap_rows(pivot_row, pivot);
+
+                let mut reduced = true;
+                for row in pivot_row + 1..rows {
+                    let quotient = h[(row, col)].div_rem(&h[(pivot_row, col)]).0;
+                    sub_row(&mut h, row, pivot_row, &quotient);
+                    sub_row(&mut transform, row, pivot_row, &quotient);
+                    reduced &= h[(row, col)].is_zero();
+                }
+                if reduced {
+                    break;
+                }
+            }
+            if h[(pivot_row, col)].is_zero() {
+                continue;
+            }
+
+            let leading = h[(pivot_row, col)].coefficients()[h[(pivot_row, col)].degree()].clone();
+            scale_row(&mut h, pivot_row, T::one() / leading.clone());
+            scale_row(&mut transform, pivot_row, T::one() / leading);
+            for row in 0..pivot_row {
+                let quotient = h[(row, col)].div_rem(&h[(pivot_row, col)]).0;
+                sub_row(&mut h, row, pivot_row, &quotient);
+                sub_row(&mut transform, row, pivot_row, &quotient);
+            }
+            pivot_row += 1;
+        }
+
+        HermiteNormalForm {
+            transform,
+            normal_form: h,
+        }
+    }
+
+    /// Returns the invariant factors of the matrix, see [`SmithNormalForm::invariant_factors`].
+    pub fn invariant_factors(&self) -> Vec<Polynomial<T>> {
+        self.smith_normal_form().invariant_factors()
+    }
+}
+
+impl Matrix<Polynomial<RationalNumber>> {
+    /// Returns the elementary divisors of the matrix: the prime powers the invariant factors split into.
+    ///
+    /// The invariant factors are split over a coprime base built from their square-free decompositions,
+    /// and the rational roots of every base element are split off as linear factors.
+    /// The factors without rational roots are not factored further, so these are the usual elementary divisors
+    /// over `Q` whenever those factors are irreducible, e.g. when all the eigenvalues are rational.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::{Matrix, RationalNumber};
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// use num::Zero;
+    /// let r = |n| RationalNumber::new(n, 1);
+    /// let x = Polynomial::x();
+    /// let m = Matrix::from_rows(vec![
+    ///     vec![x.clone() - r(1), Polynomial::zero()],
+    ///     vec![Polynomial::zero(), x.clone() - r(2)],
+    /// ]);
+    /// // the only non-trivial invariant factor is (x - 1)(x - 2)
+    /// assert_eq!(m.elementary_divisors(), vec![x.clone() - r(1), x - r(2)]);
+    /// ```
+    pub fn elementary_divisors(&self) -> Vec<Polynomial<RationalNumber>> {
+        let factors = self.invariant_factors();
+
+        let mut base: Vec<Polynomial<RationalNumber>> = Vec::new();
+        for factor in &factors {
+            for (square_free, _) in factor.square_free_decomposition() {
+                refine_coprime_base(&mut base, square_free);
+            }
+        }
+        let base: Vec<_> = base.into_iter().flat_map(split_rational_roots).collect();
+
+        let mut divisors = Vec::new();
+        for factor in &factors {
+            for element in &base {
+                let mut power = Polynomial::from_constant(RationalNumber::one());
+                let mut rest = factor.clone();
+                loop {
+                    let (quotient, remainder) = rest.div_rem(element);
+                    if !remainder.is_zero() {
+                        break;
+                    }
+                    power = power * element.clone();
+                    rest = quotient;
+                }
+                if power.degree() > 0 {
+                    divisors.push(power);
+                }
+            }
+        }
+        divisors
+    }
+}
+
+/// Splits a square-free monic polynomial into the linear factors `x - r` of its rational roots
+/// and the monic factor without rational roots, if it is not constant.
+///
+/// The roots `p / q` are found with the rational root theorem: `p` divides the constant term
+/// and `q` the leading coefficient of the polynomial scaled to integer coefficients.
+/// If these do not fit in `i64`, the rest of the polynomial is returned unsplit.
+fn split_rational_roots(p: Polynomial<RationalNumber>) -> Vec<Polynomial<RationalNumber>> {
+    let mut rest = p.monic();
+    let mut factors = Vec::new();
+    let x = Polynomial::<RationalNumber>::x();
+
+    if rest.degree() > 0 && rest.coefficients()[0].is_zero() {
+        rest = rest.div_rem(&x).0;
+        factors.push(x.clone());
+    }
+    if rest.degree() > 0 {
+        // the polynomial scaled to integer coefficients
+        let scale = rest.coefficients().iter().try_fold(1i64, |lcm, it| {
+            let denominator = it.denominator();
+            (lcm / num::integer::gcd(lcm, denominator)).checked_mul(denominator)
+        });
+        let integer = |it: &RationalNumber| {
+            scale.and_then(|scale| it.numerator().checked_mul(scale / it.denominator()))
+        };
+        let constant = integer(&rest.coefficients()[0]);
+        let leading = integer(&rest.coefficients()[rest.degree()]);
+        let (Some(constant), Some(leading)) = (constant, leading) else {
+            factors.push(rest);
+            return factors;
+        };
+        for numerator in positive_divisors(constant) {
+            for denominator in positive_divisors(leading) {
+                for sign in [1, -1] {
+                    if rest.degree() == 0 {
+                        break;
+                    }
+                    let factor = x.clone() - RationalNumber::new(sign * numerator, denominator);
+                    let (quotient, remainder) = rest.div_rem(&factor);
+                    if remainder.is_zero() && !factors.contains(&factor) {
+                        rest = quotient;
+                        factors.push(factor);
+                    }
+                }
+            }
+        }
+    }
+    if rest.degree() > 0 {
+        factors.push(rest);
+    }
+    factors
+}
+
+/// Returns the positive divisors of a non-zero integer.
+fn positive_divisors(n: i64) -> Vec<i64> {
+    let n = n.unsigned_abs();
+    (1..)
+        .take_while(|it| it * it <= n)
+        .filter(|it| n.is_multiple_of(*it))
+        .flat_map(|it| std::iter::once(it).chain((it * it != n).then_some(n / it)))
+        .map(|it| it as i64)
+        .collect()
+}
+
+/// Adds `p` to a base of pairwise coprime monic polynomials,
+/// splitting the elements with a common factor until the base is coprime again.
+fn refine_coprime_base<T>(base: &mut Vec<Polynomial<T>>, p: Polynomial<T>)
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    let mut pending = vec![p.monic()];
+    while let Some(a) = pending.pop() {
+        if a.degree() == 0 {
+            continue;
+        }
+        let common = base
+            .iter()
+            .enumerate()
+            .map(|(it, b)| (it, a.gcd(b)))
+            .find(|(_, gcd)| gcd.degree() > 0);
+        match common {
+            Some((it, gcd)) => {
+                let b = base.swap_remove(it);
+                pending.push(a.div_rem(&gcd).0.monic());
+                pending.push(b.div_rem(&gcd).0.monic());
+                pending.push(gcd);
+            }
+            None => base.push(a),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coefficients::SquareMatrix;
+
+    fn r(n: i64) -> RationalNumber {
+        RationalNumber::new(n, 1)
+    }
+
+    fn c(n: i64) -> Polynomial<RationalNumber> {
+        Polynomial::from_constant(r(n))
+    }
+
+    fn x() -> Polynomial<RationalNumber> {
+        Polynomial::x()
+    }
+
+    /// `x I - A`
+    fn characteristic_matrix<const N: usize>(
+        a: SquareMatrix<RationalNumber, N>,
+    ) -> Matrix<Polynomial<RationalNumber>> {
+        Matrix::from_fn(N, N, |row, col| {
+            let entry = Polynomial::from_constant(RationalNumber::zero() - a.data()[row][col]);
+            if row == col {
+                entry + x()
+            } else {
+                entry
+            }
+        })
+    }
+
+    /// The determinant of a unimodular matrix is a non-zero constant.
+    fn is_unimodular(m: &Matrix<Polynomial<RationalNumber>>) -> bool {
+        let det = determinant(m);
+        m.is_square() && det.degree() == 0 && !det.is_zero()
+    }
+
+    fn determinant(m: &Matrix<Polynomial<RationalNumber>>) -> Polynomial<RationalNumber> {
+        let n = m.rows();
+        if n == 0 {
+            return c(1);
+        }
+        // Laplace expansion along the first row, the matrices in the tests are small
+        let mut sum = c(0);
+        for col in 0..n {
+            let minor = Matrix::from_fn(n - 1, n - 1, |row, it| {
+                m[(row + 1, if it < col { it } else { it + 1 })].clone()
+            });
+            let term = m[(0, col)].clone() * determinant(&minor);
+            sum = if col % 2 == 0 { sum + term } else { sum - term };
+        }
+        sum
+    }
+
+    #[test]
+    fn test_smith_normal_form_of_characteristic_matrix() {
+        // two Jordan blocks for 2 of sizes 2 and 1, and one for 3 of size 1
+        let a = SquareMatrix::new([
+            [r(2), r(1), r(0), r(0)],
+            [r(0), r(2), r(0), r(0)],
+            [r(0), r(0), r(2), r(0)],
+            [r(0), r(0), r(0), r(3)],
+        ]);
+        let m = characteristic_matrix(a.clone());
+        let snf = m.smith_normal_form();
+
+        assert_eq!(
+            snf.left().clone() * m.clone() * snf.right().clone(),
+            snf.normal_form().clone()
+        );
+        assert!(is_unimodular(snf.left()));
+        assert!(is_unimodular(snf.right()));
+
+        let x_2 = x() - r(2);
+        let x_3 = x() - r(3);
+        assert_eq!(
+            snf.invariant_factors(),
+            vec![
+                c(1),
+                c(1),
+                x_2.clone(),
+                x_2.clone() * x_2.clone() * x_3.clone()
+            ]
+        );
+        // the last invariant factor is the minimal polynomial
+        assert_eq!(snf.invariant_factors()[3], a.minimal_polynomial());
+
+        let mut divisors = m.elementary_divisors();
+        divisors.sort_by_key(|it| it.degree());
+        assert_eq!(divisors, vec![x_2.clone(), x_3, x_2.clone() * x_2]);
+    }
+
+    #[test]
+    fn test_smith_normal_form_rectangular() {
+        let m = Matrix::from_rows(vec![
+            vec![x(), x() * x(), c(0)],
+            vec![x() * x(), x() + c(1), x()],
+        ]);
+        let snf = m.smith_normal_form();
+        assert_eq!(
+            snf.left().clone() * m.clone() * snf.right().clone(),
+            snf.normal_form().clone()
+        );
+        let s = snf.normal_form();
+        assert_eq!(s.shape(), Some((2, 3)));
+        for row in 0..2 {
+            for col in 0..3 {
+                if row != col {
+                    assert!(s[(row, col)].is_zero());
+                }
+            }
+        }
+        let factors = snf.invariant_factors();
+        assert!(factors[1].div_rem(&factors[0]).1.is_zero());
+    }
+
+    #[test]
+    fn test_smith_normal_form_singular() {
+        let m = Matrix::from_rows(vec![
+            vec![x(), x() + c(1)],
+            vec![x() * c(2), x() * c(2) + c(2)],
+        ]);
+        let snf = m.smith_normal_form();
+        assert_eq!(snf.invariant_factors().len(), 1);
+        assert!(snf.normal_form()[(1, 1)].is_zero());
+        assert_eq!(
+            snf.left().clone() * m * snf.right().clone(),
+            snf.normal_form().clone()
+        );
+    }
+
+    #[test]
+    fn test_hermite_normal_form() {
+        let m = Matrix::from_rows(vec![
+            vec![x() + c(1), x() * x(), c(2)],
+            vec![x() - c(1), c(3), x()],
+            vec![c(1), x(), x() * x() + c(1)],
+        ]);
+        let hnf = m.hermite_normal_form();
+        let h = hnf.normal_form();
+        assert_eq!(hnf.transform().clone() * m, h.clone());
+        assert!(is_unimodular(hnf.transform()));
+        for row in 0..3 {
+            // monic pivots on the diagonal for a non-singular matrix
+            let pivot = &h[(row, row)];
+            assert_eq!(pivot.coefficients()[pivot.degree()], r(1));
+            for other in 0..3 {
+                if other > row {
+                    assert!(h[(other, row)].is_zero());
+                } else if other < row {
+                    assert!(h[(other, row)].degree() < pivot.degree() || h[(other, row)].is_zero());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_coprime_base() {
+        let mut base = Vec::new();
+        refine_coprime_base(&mut base, (x() - r(1)) * (x() - r(2)));
+        refine_coprime_base(&mut base, (x() - r(2)) * (x() - r(3)));
+        base.sort_by_key(|it| it.coefficients()[0].numerator());
+        assert_eq!(base, vec![x() - r(3), x() - r(2), x() - r(1)]);
+    }
+
+    #[test]
+    fn test_elementary_divisors_split_rational_roots() {
+        let m = Matrix::from_rows(vec![vec![x() - r(1), c(0)], vec![c(0), x() - r(2)]]);
+        assert_eq!(
+            m.invariant_factors(),
+            vec![c(1), (x() - r(1)) * (x() - r(2))]
+        );
+        assert_eq!(m.elementary_divisors(), vec![x() - r(1), x() - r(2)]);
+    }
+
+    #[test]
+    fn test_split_rational_roots() {
+        let half = x() - RationalNumber::new(1, 2);
+        let x2_1 = x() * x() + c(1);
+        let factors = split_rational_roots(x() * half.clone() * (x() + r(3)) * x2_1.clone());
+        assert_eq!(factors, vec![x(), half, x() + r(3), x2_1.clone()]);
+        assert_eq!(split_rational_roots(x2_1.clone()), vec![x2_1]);
+        assert_eq!(positive_divisors(-12).len(), 6);
+        assert_eq!(positive_divisors(4), vec![1, 4, 2]);
+        assert_eq!(positive_divisors(1), vec![1]);
+        // the coefficients scaled to integers overflow i64, the polynomial is then kept whole
+        let overflowing = Polynomial::from_vector(vec![
+            RationalNumber::new(1, (1 << 32) + 1),
+            RationalNumber::new(1, (1 << 32) + 3),
+            r(1),
+        ]);
+        assert_eq!(split_rational_roots(overflowing.clone()), vec![overflowing]);
+    }
+}
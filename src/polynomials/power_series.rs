@@ -0,0 +1,491 @@
+#![forbid(unsafe_code)]
+//! # Power series
+//!
+//! Truncated formal power series `a_0 + a_1 x + ... + a_(n-1) x^(n-1) + O(x^n)`.
+//!
+//! The inverse, the square root, the exponential and the reversion are computed with Newton iterations,
+//! which double the number of correct coefficients at every step.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use num::{One, Zero};
+
+use super::Polynomial;
+
+/// A formal power series known up to the precision `O(x^n)`.
+/// Only the coefficients of `x^0, ..., x^(n-1)` are stored, and every operation
+/// keeps track of how many coefficients of its result are exact.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PowerSeries<T>
+where
+    T: One + Zero + Clone,
+{
+    polynomial: Polynomial<T>,
+    precision: usize,
+}
+
+/// Returns the coefficients of `a b` up to `x^(n-1)`.
+fn mul_truncated<T>(a: &[T], b: &[T], n: usize) -> Vec<T>
+where
+    T: Clone + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    let mut coefficients = vec![T::zero(); n.min(a.len() + b.len() - 1)];
+    for (i, a) in a.iter().enumerate().take(coefficients.len()) {
+        for (j, b) in b.iter().enumerate().take(coefficients.len() - i) {
+            coefficients[i + j] = coefficients[i + j].clone() + a.clone() * b.clone();
+        }
+    }
+    coefficients
+}
+
+impl<T> PowerSeries<T>
+where
+    T: Clone + Zero + One,
+{
+    /// Create a new `PowerSeries` from a polynomial and the precision `n` of `O(x^n)`.
+    /// The terms of the polynomial of degree `n` and above are dropped.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::{Polynomial, PowerSeries};
+    /// let s = PowerSeries::new(Polynomial::from_vector(vec![1, 2, 3]), 2); // 1 + 2x + O(x^2)
+    /// assert_eq!(s.polynomial(), &Polynomial::from_vector(vec![1, 2]));
+    /// ```
+    pub fn new(polynomial: Polynomial<T>, precision: usize) -> Self {
+        let coefficients: Vec<T> = polynomial
+            .coefficients()
+            .iter()
+            .take(precision)
+            .cloned()
+            .collect();
+        let polynomial = if coefficients.is_empty() {
+            Polynomial::zero()
+        } else {
+            Polynomial::from_vector(coefficients)
+        };
+        Self {
+            polynomial,
+            precision,
+        }
+    }
+
+    /// Returns the known part of the series as a polynomial.
+    pub fn polynomial(&self) -> &Polynomial<T> {
+        &self.polynomial
+    }
+
+    /// Returns the precision `n` of the series, i.e. the error term is `O(x^n)`.
+    pub fn precision(&self) -> usize {
+        self.precision
+    }
+
+    /// Returns the coefficient of `x^k`.
+    /// # Panics
+    /// Panics if `k` is not below the precision.
+    pub fn coefficient(&self, k: usize) -> T {
+        assert!(k < self.precision, "coefficient beyond the precision");
+        self.polynomial
+            .coefficients()
+            .get(k)
+            .cloned()
+            .unwrap_or_else(T::zero)
+    }
+
+    /// Returns the series with its precision lowered to `precision`.
+    /// The precision is never raised, as the missing coefficients are unknown.
+    pub fn truncate(&self, precision: usize) -> Self {
+        Self::new(self.polynomial.clone(), precision.min(self.precision))
+    }
+
+    /// Returns the known part of the series with the precision `precision`,
+    /// treating the missing coefficients as zeros.
+    /// Only used where the Newton iterations do not depend on those coefficients.
+    fn extend(&self, precision: usize) -> Self {
+        Self::new(self.polynomial.clone(), precision)
+    }
+}
+
+impl<T> PowerSeries<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Returns the formal derivative of the series, whose precision is one less.
+    pub fn derivative(&self) -> Self {
+        Self::new(
+            self.polynomial.derivative(),
+            self.precision.saturating_sub(1),
+        )
+    }
+
+    /// Returns the composition `f(g(x))` of the series `f` with a series `g` without constant term.
+    /// The precision of the result is the smaller of the two precisions.
+    /// # Panics
+    /// Panics if the constant term of `g` is not zero.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::polynomials::{Polynomial, PowerSeries};
+    /// let f = PowerSeries::new(Polynomial::from_vector(vec![1, 1, 1, 1]), 4); // 1 + x + x^2 + x^3
+    /// let g = PowerSeries::new(Polynomial::from_vector(vec![0, 2]), 4); // 2x
+    /// assert_eq!(f.compose(&g).polynomial(), &Polynomial::from_vector(vec![1, 2, 4, 8]));
+    /// ```
+    pub fn compose(&self, g: &Self) -> Self {
+        assert!(
+            g.precision == 0 || g.coefficient(0).is_zero(),
+            "the inner series must have no constant term"
+        );
+        let precision = self.precision.min(g.precision);
+        let mut result = Self::new(Polynomial::zero(), precision);
+        for coeff in self.polynomial.coefficients().iter().rev() {
+            result = result * g.clone() + coeff.clone();
+        }
+        result.truncate(precision)
+    }
+}
+
+impl<T> PowerSeries<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Returns the multiplicative inverse of the series, computed with the Newton iteration `g <- g (2 - f g)`.
+    /// # Panics
+    /// Panics if the constant term is zero.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::RationalNumber;
+    /// use rust_polynomials_lib::polynomials::{Polynomial, PowerSeries};
+    /// let r = |n| RationalNumber::new(n, 1);
+    /// let s = PowerSeries::new(Polynomial::from_vector(vec![r(1), r(-1)]), 5); // 1 - x
+    /// let geometric = Polynomial::from_vector(vec![r(1); 5]);
+    /// assert_eq!(s.inverse().polynomial(), &geometric);
+    /// ```
+    pub fn inverse(&self) -> Self {
+        assert!(
+            self.precision > 0 && !self.coefficient(0).is_zero(),
+            "the constant term must be invertible"
+        );
+        let two = T::one() + T::one();
+        let mut g = Self::new(Polynomial::from_constant(T::one() / self.coefficient(0)), 1);
+        while g.precision < self.precision {
+            let precision = (2 * g.precision).min(self.precision);
+            let g_ext = g.extend(precision);
+            let correction = Self::new(Polynomial::from_constant(two.clone()), precision)
+                - self.truncate(precision) * g_ext.clone();
+            g = g_ext * correction;
+        }
+        g
+    }
+
+    /// Returns the formal integral of the series with zero constant term, whose precision is one more.
+    pub fn integral(&self) -> Self {
+        let mut coefficients = vec![T::zero()];
+        let mut k = T::zero();
+        for coeff in self.polynomial.coefficients() {
+            k = k + T::one();
+            coefficients.push(coeff.clone() / k.clone());
+        }
+        Self::new(Polynomial::from_vector(coefficients), self.precision + 1)
+    }
+
+    /// Returns the square root of the series with constant term one,
+    /// computed with the Newton iteration `g <- (g + f / g) / 2`.
+    /// # Panics
+    /// Panics if the constant term is not one.
+    pub fn sqrt(&self) -> Self {
+        self.assert_constant_term(T::one(), "the constant term must be one");
+        let half = T::one() / (T::one() + T::one());
+        let mut g = Self::new(Polynomial::from_constant(T::one()), 1);
+        while g.precision < self.precision {
+            let precision = (2 * g.precision).min(self.precision);
+            let g_ext = g.extend(precision);
+            g = (g_ext.clone() + self.truncate(precision) / g_ext) * half.clone();
+        }
+        g
+    }
+
+    /// Returns the logarithm of the series with constant term one, as the integral of `f' / f`.
+    /// # Panics
+    /// Panics if the constant term is not one.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::RationalNumber;
+    /// use rust_polynomials_lib::polynomials::{Polynomial, PowerSeries};
+    /// let r = |n| RationalNumber::new(n, 1);
+    /// let s = PowerSeries::new(Polynomial::from_vector(vec![r(1), r(1)]), 4); // 1 + x
+    /// let log = Polynomial::from_vector(vec![r(0), r(1), RationalNumber::new(-1, 2), RationalNumber::new(1, 3)]);
+    /// assert_eq!(s.log().polynomial(), &log);
+    /// ```
+    pub fn log(&self) -> Self {
+        self.assert_constant_term(T::one(), "the constant term must be one");
+        (self.derivative() / self.clone()).integral()
+    }
+
+    /// Returns the exponential of the series with constant term zero,
+    /// computed with the Newton iteration `g <- g (1 - log g + f)`.
+    /// # Panics
+    /// Panics if the constant term is not zero.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::RationalNumber;
+    /// use rust_polynomials_lib::polynomials::{Polynomial, PowerSeries};
+    /// let r = |n, d| RationalNumber::new(n, d);
+    /// let s = PowerSeries::new(Polynomial::x(), 4); // x
+    /// let exp = Polynomial::from_vector(vec![r(1, 1), r(1, 1), r(1, 2), r(1, 6)]);
+    /// assert_eq!(s.exp().polynomial(), &exp);
+    /// ```
+    pub fn exp(&self) -> Self {
+        self.assert_constant_term(T::zero(), "the constant term must be zero");
+        let mut g = Self::new(Polynomial::from_constant(T::one()), 1);
+        while g.precision < self.precision {
+            let precision = (2 * g.precision).min(self.precision);
+            let g_ext = g.extend(precision);
+            let correction = self.truncate(precision) - g_ext.log() + T::one();
+            g = g_ext * correction;
+        }
+        g
+    }
+
+    /// Returns the compositional inverse `g` of the series `f`, such that `f(g(x)) = g(f(x)) = x`,
+    /// computed with the Newton iteration `g <- g - (f(g) - x) / f'(g)`.
+    /// # Panics
+    /// Panics if the constant term is not zero or the coefficient of `x` is zero.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::RationalNumber;
+    /// use rust_polynomials_lib::polynomials::{Polynomial, PowerSeries};
+    /// let r = |n| RationalNumber::new(n, 1);
+    /// let f = PowerSeries::new(Polynomial::from_vector(vec![r(0), r(1), r(-1)]), 5); // x - x^2
+    /// // the generating function of the Catalan numbers: x + x^2 + 2x^3 + 5x^4
+    /// let catalan = Polynomial::from_vector(vec![r(0), r(1), r(1), r(2), r(5)]);
+    /// assert_eq!(f.reversion().polynomial(), &catalan);
+    /// ```
+    pub fn reversion(&self) -> Self {
+        self.assert_constant_term(T::zero(), "the constant term must be zero");
+        assert!(
+            self.precision > 1 && !self.coefficient(1).is_zero(),
+            "the coefficient of x must be invertible"
+        );
+        let derivative = self.polynomial.derivative();
+        let x = Polynomial::x();
+        let mut g = Self::new(x.clone() * (T::one() / self.coefficient(1)), 2);
+        while g.precision < self.precision {
+            let precision = (2 * g.precision).min(self.precision);
+            let g_ext = g.extend(precision);
+            // f(g) - x vanishes up to x^(p - 1) for the previous precision p,
+            // so f'(g) is only needed to the precision of the previous step
+            let residual =
+                self.truncate(precision).compose(&g_ext) - Self::new(x.clone(), precision);
+            let slope = Self::new(derivative.clone(), precision).compose(&g_ext);
+            g = g_ext - residual / slope;
+        }
+        g
+    }
+
+    fn assert_constant_term(&self, expected: T, message: &str) {
+        let constant = if self.precision == 0 {
+            expected.clone()
+        } else {
+            self.coefficient(0)
+        };
+        assert!((constant - expected).is_zero(), "{}", message);
+    }
+}
+
+impl<T> Add for PowerSeries<T>
+where
+    T: Clone + Zero + One + Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let precision = self.precision.min(rhs.precision);
+        Self::new(self.polynomial + rhs.polynomial, precision)
+    }
+}
+
+impl<T> Add<T> for PowerSeries<T>
+where
+    T: Clone + Zero + One + Add<Output = T>,
+{
+    type Output = Self;
+
+    /// adding rhs constant to the constant term of the series
+    fn add(self, rhs: T) -> Self {
+        let precision = self.precision;
+        Self::new(self.polynomial + rhs, precision)
+    }
+}
+
+impl<T> Sub for PowerSeries<T>
+where
+    T: Clone + Zero + One + Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let precision = self.precision.min(rhs.precision);
+        Self::new(self.polynomial - rhs.polynomial, precision)
+    }
+}
+
+impl<T> Neg for PowerSeries<T>
+where
+    T: Clone + Zero + One + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            polynomial: -self.polynomial,
+            precision: self.precision,
+        }
+    }
+}
+
+impl<T> Mul for PowerSeries<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let precision = self.precision.min(rhs.precision);
+        let coefficients = mul_truncated(
+            self.polynomial.coefficients(),
+            rhs.polynomial.coefficients(),
+            precision,
+        );
+        Self::new(Polynomial::from_vector(coefficients), precision)
+    }
+}
+
+impl<T> Mul<T> for PowerSeries<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Self {
+            polynomial: self.polynomial * rhs,
+            precision: self.precision,
+        }
+    }
+}
+
+/// Multiplication by the inverse, see [`PowerSeries::inverse`].
+impl<T> Div for PowerSeries<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inverse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coefficients::RationalNumber;
+
+    fn r(n: i64, d: i64) -> RationalNumber {
+        RationalNumber::new(n, d)
+    }
+
+    fn series(coefficients: &[i64], precision: usize) -> PowerSeries<RationalNumber> {
+        PowerSeries::new(
+            Polynomial::from_vector(coefficients.iter().map(|&c| r(c, 1)).collect()),
+            precision,
+        )
+    }
+
+    #[test]
+    fn test_new_truncates() {
+        let s = series(&[1, 2, 3, 4], 2);
+        assert_eq!(
+            s.polynomial(),
+            &Polynomial::from_vector(vec![r(1, 1), r(2, 1)])
+        );
+        assert_eq!(s.coefficient(1), r(2, 1));
+        assert_eq!(series(&[1, 2], 5).coefficient(4), r(0, 1));
+        assert!(series(&[1, 2], 0).polynomial().is_zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "coefficient beyond the precision")]
+    fn test_coefficient_beyond_precision() {
+        series(&[1, 2], 2).coefficient(2);
+    }
+
+    #[test]
+    fn test_arithmetic_precision() {
+        let a = series(&[1, 1, 1, 1, 1], 5);
+        let b = series(&[1, -1], 3);
+        assert_eq!((a.clone() + b.clone()).precision(), 3);
+        assert_eq!(a.clone() * b.clone(), series(&[1], 3));
+        assert_eq!(-(a.clone() - b), series(&[0, -2, -1], 3));
+        assert_eq!(a * r(2, 1), series(&[2, 2, 2, 2, 2], 5));
+    }
+
+    #[test]
+    fn test_inverse() {
+        let s = series(&[2, 3, -1, 5], 10);
+        let inverse = s.inverse();
+        assert_eq!(inverse.precision(), 10);
+        assert_eq!(s.clone() * inverse, series(&[1], 10));
+        assert_eq!(s.clone() / s, series(&[1], 10));
+    }
+
+    #[test]
+    #[should_panic(expected = "the constant term must be invertible")]
+    fn test_inverse_without_constant_term() {
+        series(&[0, 1], 3).inverse();
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let s = series(&[1, 4, -2, 7], 8);
+        let root = s.sqrt();
+        assert_eq!(root.clone() * root, s.truncate(8));
+        // sqrt(1 + x) = 1 + x/2 - x^2/8 + x^3/16
+        let expected = Polynomial::from_vector(vec![r(1, 1), r(1, 2), r(-1, 8), r(1, 16)]);
+        assert_eq!(series(&[1, 1], 4).sqrt().polynomial(), &expected);
+    }
+
+    #[test]
+    fn test_exp_log() {
+        let s = series(&[0, 1, -3, 2], 9);
+        assert_eq!(s.exp().log(), s);
+        let t = series(&[1, 2, 0, -1], 9);
+        assert_eq!(t.log().exp(), t);
+        // exp(a + b) = exp(a) exp(b)
+        let u = series(&[0, 0, 5, 1], 9);
+        assert_eq!((s.clone() + u.clone()).exp(), s.exp() * u.exp());
+    }
+
+    #[test]
+    #[should_panic(expected = "the constant term must be zero")]
+    fn test_exp_with_constant_term() {
+        series(&[1, 1], 3).exp();
+    }
+
+    #[test]
+    fn test_compose_and_reversion() {
+        let f = series(&[0, 2, 1, -3, 4], 7);
+        let g = f.reversion();
+        let x = series(&[0, 1], 7);
+        assert_eq!(f.compose(&g), x);
+        assert_eq!(g.compose(&f), x);
+    }
+
+    #[test]
+    fn test_f64_series() {
+        let s = PowerSeries::new(Polynomial::from_vector(vec![0.0, 1.0]), 10);
+        let exp = s.exp();
+        for k in 0..10 {
+            let factorial: f64 = (1..=k).map(|it| it as f64).product();
+            assert!((exp.coefficient(k) - 1.0 / factorial).abs() < 1e-15);
+        }
+    }
+}
@@ -0,0 +1,155 @@
+#![forbid(unsafe_code)]
+//! # Range evaluation
+//!
+//! Verified enclosures of the range of an `f64` polynomial over an interval.
+//! All the computations are carried out in interval arithmetic, so rounding errors are accounted for.
+
+use num::{One, Zero};
+
+use super::Polynomial;
+use crate::coefficients::Interval;
+
+impl Polynomial<f64> {
+    /// Returns the polynomial with every coefficient turned into a point interval.
+    pub fn to_intervals(&self) -> Polynomial<Interval> {
+        Polynomial::from_vector(
+            self.coefficients()
+                .iter()
+                .map(|&c| Interval::point(c))
+                .collect(),
+        )
+    }
+
+    /// Encloses the range of the polynomial over `x` with the centered (mean value) form
+    /// `p(c) + p'(x) (x - c)`, where `c` is the midpoint of `x`.
+    /// The overestimation shrinks quadratically with the width of `x`.
+    pub fn range_centered(&self, x: Interval) -> Interval {
+        let p = self.to_intervals();
+        let center = Interval::point(x.midpoint());
+        p.eval(center) + p.derivative().eval(x) * (x - center)
+    }
+
+    /// Encloses the range of the polynomial over `x` with the coefficients of its Bernstein form on `x`.
+    /// The range lies between the smallest and the largest Bernstein coefficient.
+    pub fn range_bernstein(&self, x: Interval) -> Interval {
+        let p = self.to_intervals();
+
+        // q(t) = p(lo + (hi - lo) t), so that the Bernstein form is taken on [0, 1]
+        let substitution = Polynomial::from_vector(vec![
+            Interval::point(x.lo()),
+            Interval::point(x.hi()) - Interval::point(x.lo()),
+        ]);
+        let q = p.eval(substitution);
+        let q = q.coefficients();
+        let degree = q.len() - 1;
+
+        // binomials[k][i] = C(k, i)
+        let mut binomials = vec![vec![Interval::one()]];
+        for k in 1..=degree {
+            let mut row = vec![Interval::one(); k + 1];
+            for i in 1..k {
+                row[i] = binomials[k - 1][i - 1] + binomials[k - 1][i];
+            }
+            binomials.push(row);
+        }
+
+        let mut range: Option<Interval> = None;
+        for k in 0..=degree {
+            let mut coefficient = Interval::zero();
+            for i in 0..=k {
+                coefficient = coefficient + binomials[k][i] / binomials[degree][i] * q[i];
+            }
+            range = Some(match range {
+                Some(range) => range.hull(&coefficient),
+                None => coefficient,
+            });
+        }
+        range.unwrap_or(Interval::zero())
+    }
+
+    /// Encloses the range of the polynomial over `x` as tightly as the natural (Horner), centered
+    /// and Bernstein forms allow together.
+    /// The result is guaranteed to contain `p(t)` for every `t` in `x`.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::Interval;
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let p = Polynomial::from_vector(vec![0.0, -2.0, 1.0]); // x^2 - 2x, ranges over [-1, 0] on [0, 2]
+    /// let range = p.range(Interval::new(0.0, 2.0));
+    /// assert!(range.encloses(&Interval::new(-1.0, 0.0)));
+    /// ```
+    pub fn range(&self, x: Interval) -> Interval {
+        let natural = self.to_intervals().eval(x);
+        [self.range_centered(x), self.range_bernstein(x)]
+            .iter()
+            .fold(natural, |range, other| {
+                range.intersection(other).unwrap_or(range)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Polynomial<f64> {
+        // 1 - 3x + x^3
+        Polynomial::from_vector(vec![1.0, -3.0, 0.0, 1.0])
+    }
+
+    fn sampled_range(p: &Polynomial<f64>, x: Interval) -> (f64, f64) {
+        let mut lo = f64::INFINITY;
+        let mut hi = f64::NEG_INFINITY;
+        for it in 0..=1000 {
+            let t = x.lo() + (x.hi() - x.lo()) * it as f64 / 1000.0;
+            lo = lo.min(p.eval(t));
+            hi = hi.max(p.eval(t));
+        }
+        (lo, hi)
+    }
+
+    #[test]
+    fn test_range_encloses_samples() {
+        let p = sample();
+        for x in [
+            Interval::new(-2.0, 2.0),
+            Interval::new(0.0, 1.0),
+            Interval::new(0.9, 1.1),
+            Interval::new(-0.3, 0.1),
+        ] {
+            let (lo, hi) = sampled_range(&p, x);
+            for range in [
+                p.to_intervals().eval(x),
+                p.range_centered(x),
+                p.range_bernstein(x),
+                p.range(x),
+            ] {
+                assert!(range.contains(lo) && range.contains(hi));
+            }
+        }
+    }
+
+    #[test]
+    fn test_range_is_tighter_than_natural() {
+        let p = sample();
+        let x = Interval::new(0.9, 1.1);
+        let natural = p.to_intervals().eval(x);
+        let range = p.range(x);
+        assert!(range.width() < natural.width() / 4.0);
+        // the true range is [-1, -0.969]
+        assert!(range.width() < 0.1);
+    }
+
+    #[test]
+    fn test_range_bernstein_is_exact_on_monotone_parts() {
+        let p = Polynomial::from_vector(vec![0.0, 0.0, 1.0]); // x^2
+        let range = p.range_bernstein(Interval::new(0.0, 1.0));
+        assert_eq!(range, Interval::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_range_of_constant() {
+        let p = Polynomial::from_constant(3.0);
+        assert_eq!(p.range(Interval::new(-1.0, 1.0)), Interval::point(3.0));
+    }
+}
@@ -0,0 +1,300 @@
+#![forbid(unsafe_code)]
+//! # Rational functions
+//!
+//! Quotients `P / Q` of polynomials over a field with exact arithmetic, such as `RationalNumber`.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use num::{One, Zero};
+
+use super::partial_fractions::PartialFractions;
+use super::Polynomial;
+
+/// A rational function `P / Q`.
+/// It is always kept reduced: `P` and `Q` are coprime and `Q` is monic,
+/// so two equal rational functions have the same numerator and denominator.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RationalFunction<T>
+where
+    T: One + Zero + Clone,
+{
+    numerator: Polynomial<T>,
+    denominator: Polynomial<T>,
+}
+
+impl<T> RationalFunction<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    /// Create a new `RationalFunction` `numerator / denominator`, divided by the gcd of both
+    /// and normalized to a monic denominator.
+    /// # Panics
+    /// Panics if the denominator is the zero polynomial.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::RationalNumber;
+    /// use rust_polynomials_lib::polynomials::{Polynomial, RationalFunction};
+    /// let r = |n| RationalNumber::new(n, 1);
+    /// let p = Polynomial::from_vector(vec![r(-1), r(0), r(1)]); // x^2 - 1
+    /// let q = Polynomial::from_vector(vec![r(-2), r(2)]); // 2x - 2
+    /// let f = RationalFunction::new(p, q); // (x + 1) / 2
+    /// assert_eq!(f.numerator(), &Polynomial::from_vector(vec![RationalNumber::new(1, 2), RationalNumber::new(1, 2)]));
+    /// assert_eq!(f.denominator(), &Polynomial::from_constant(r(1)));
+    /// ```
+    pub fn new(numerator: Polynomial<T>, denominator: Polynomial<T>) -> Self {
+        assert!(!denominator.is_zero(), "zero denominator");
+        if numerator.is_zero() {
+            return Self::zero();
+        }
+        let gcd = numerator.gcd(&denominator);
+        let numerator = numerator.div_rem(&gcd).0;
+        let denominator = denominator.div_rem(&gcd).0;
+        let inverse = T::one() / denominator.coefficients()[denominator.degree()].clone();
+        Self {
+            numerator: numerator * inverse.clone(),
+            denominator: denominator * inverse,
+        }
+    }
+
+    /// Returns the numerator.
+    pub fn numerator(&self) -> &Polynomial<T> {
+        &self.numerator
+    }
+
+    /// Returns the monic denominator.
+    pub fn denominator(&self) -> &Polynomial<T> {
+        &self.denominator
+    }
+
+    /// Evaluate the rational function at a given value, as the quotient of the evaluated numerator and denominator.
+    /// At a pole the denominator evaluates to zero, and the result is whatever `U` gives for a division by zero.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::Complex;
+    /// use rust_polynomials_lib::polynomials::{Polynomial, RationalFunction};
+    /// // 1 / (1 + x)
+    /// let f = RationalFunction::new(Polynomial::from_constant(1.0), Polynomial::from_vector(vec![1.0, 1.0]));
+    /// assert_eq!(f.eval(1.0), 0.5);
+    /// assert_eq!(f.eval(Complex::new(0.0, 1.0)), Complex::new(0.5, -0.5));
+    /// ```
+    pub fn eval<U>(&self, x: U) -> U
+    where
+        U: Clone
+            + Zero
+            + One
+            + Add<Output = U>
+            + Mul<Output = U>
+            + Div<Output = U>
+            + Mul<T, Output = U>,
+    {
+        self.numerator.eval(x.clone()) / self.denominator.eval(x)
+    }
+
+    /// Returns the derivative `(P' Q - P Q') / Q^2`, reduced.
+    pub fn derivative(&self) -> Self {
+        Self::new(
+            self.numerator.derivative() * self.denominator.clone()
+                - self.numerator.clone() * self.denominator.derivative(),
+            self.denominator.clone() * self.denominator.clone(),
+        )
+    }
+
+    /// Returns the partial fraction decomposition of the rational function,
+    /// see [`PartialFractions`].
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::RationalNumber;
+    /// use rust_polynomials_lib::polynomials::{Polynomial, RationalFunction};
+    /// let r = |n| RationalNumber::new(n, 1);
+    /// // (2x^3 + 1) / (x^2 - 1) = 2x + (2x + 1) / (x^2 - 1)
+    /// let f = RationalFunction::new(
+    ///     Polynomial::from_vector(vec![r(1), r(0), r(0), r(2)]),
+    ///     Polynomial::from_vector(vec![r(-1), r(0), r(1)]),
+    /// );
+    /// let fractions = f.partial_fractions();
+    /// assert_eq!(fractions.polynomial(), &Polynomial::from_vector(vec![r(0), r(2)]));
+    /// // x^2 - 1 is square-free, so it is kept as a single factor
+    /// assert_eq!(fractions.terms().len(), 1);
+    /// assert_eq!(fractions.to_rational_function(), f);
+    /// ```
+    pub fn partial_fractions(&self) -> PartialFractions<T> {
+        PartialFractions::new(&self.numerator, &self.denominator)
+    }
+}
+
+impl<T> From<Polynomial<T>> for RationalFunction<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    fn from(polynomial: Polynomial<T>) -> Self {
+        Self::new(polynomial, Polynomial::from_constant(T::one()))
+    }
+}
+
+impl<T> Add for RationalFunction<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(
+            self.numerator * rhs.denominator.clone() + rhs.numerator * self.denominator.clone(),
+            self.denominator * rhs.denominator,
+        )
+    }
+}
+
+impl<T> Sub for RationalFunction<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(
+            self.numerator * rhs.denominator.clone() - rhs.numerator * self.denominator.clone(),
+            self.denominator * rhs.denominator,
+        )
+    }
+}
+
+impl<T> Neg for RationalFunction<T>
+where
+    T: Clone + Zero + One + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl<T> Mul for RationalFunction<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.numerator * rhs.numerator,
+            self.denominator * rhs.denominator,
+        )
+    }
+}
+
+impl<T> Div for RationalFunction<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    type Output = Self;
+
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: Self) -> Self {
+        assert!(!rhs.is_zero(), "division by zero rational function");
+        Self::new(
+            self.numerator * rhs.denominator,
+            self.denominator * rhs.numerator,
+        )
+    }
+}
+
+impl<T> Zero for RationalFunction<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    fn zero() -> Self {
+        Self {
+            numerator: Polynomial::zero(),
+            denominator: Polynomial::from_constant(T::one()),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+}
+
+impl<T> One for RationalFunction<T>
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    fn one() -> Self {
+        Self {
+            numerator: Polynomial::from_constant(T::one()),
+            denominator: Polynomial::from_constant(T::one()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coefficients::RationalNumber;
+
+    fn r(n: i64, d: i64) -> RationalNumber {
+        RationalNumber::new(n, d)
+    }
+
+    fn poly(coefficients: &[i64]) -> Polynomial<RationalNumber> {
+        Polynomial::from_vector(coefficients.iter().map(|&c| r(c, 1)).collect())
+    }
+
+    fn f(numerator: &[i64], denominator: &[i64]) -> RationalFunction<RationalNumber> {
+        RationalFunction::new(poly(numerator), poly(denominator))
+    }
+
+    #[test]
+    fn test_new_reduces() {
+        // (x - 1)(x + 2) / (3 (x - 1)(x + 5))
+        let a = f(&[-2, 1, 1], &[-15, 12, 3]);
+        assert_eq!(
+            a.numerator(),
+            &Polynomial::from_vector(vec![r(2, 3), r(1, 3)])
+        );
+        assert_eq!(a.denominator(), &poly(&[5, 1]));
+        assert_eq!(f(&[0], &[1, 2]), RationalFunction::zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "zero denominator")]
+    fn test_zero_denominator() {
+        f(&[1], &[0]);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = f(&[1], &[-1, 1]); // 1 / (x - 1)
+        let b = f(&[1], &[1, 1]); // 1 / (x + 1)
+        assert_eq!(a.clone() + b.clone(), f(&[0, 2], &[-1, 0, 1]));
+        assert_eq!(a.clone() - b.clone(), f(&[2], &[-1, 0, 1]));
+        assert_eq!(a.clone() * b.clone(), f(&[1], &[-1, 0, 1]));
+        assert_eq!(a.clone() / b.clone(), f(&[1, 1], &[-1, 1]));
+        assert_eq!(a.clone() / a.clone(), RationalFunction::one());
+        assert!((a.clone() - a.clone()).is_zero());
+        assert_eq!(-a.clone() + a, RationalFunction::zero());
+    }
+
+    #[test]
+    fn test_eval_and_derivative() {
+        let a = f(&[1, 0, 1], &[-1, 1]); // (1 + x^2) / (x - 1)
+        assert_eq!(a.eval(r(3, 1)), r(5, 1));
+        // (x^2 - 2x - 1) / (x - 1)^2
+        assert_eq!(a.derivative(), f(&[-1, -2, 1], &[1, -2, 1]));
+        assert_eq!(
+            RationalFunction::from(poly(&[1, 2, 3])).derivative(),
+            RationalFunction::from(poly(&[2, 6]))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "division by zero rational function")]
+    fn test_division_by_zero() {
+        let _ = f(&[1], &[1]) / RationalFunction::zero();
+    }
+}
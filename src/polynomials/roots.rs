@@ -0,0 +1,138 @@
+#![forbid(unsafe_code)]
+//! # Root finding
+//!
+//! All the complex roots of a polynomial at once with the Aberth–Ehrlich iteration,
+//! following Bini, "Numerical computation of polynomial zeros by means of Aberth's method" (1996).
+
+use std::f64::consts::TAU;
+
+use num::{One, Zero};
+
+use super::Polynomial;
+use crate::coefficients::Complex;
+
+/// The maximal number of sweeps of the Aberth–Ehrlich iteration.
+/// Simple roots converge cubically, multiple roots only linearly.
+const MAX_ITERATIONS: usize = 1000;
+
+impl Polynomial<Complex<f64>> {
+    /// Returns the roots of the polynomial, repeated according to their multiplicities.
+    /// They are computed simultaneously with the Aberth–Ehrlich iteration, starting from points on a circle
+    /// which encloses all the roots. A root of multiplicity `m` is only accurate to about `eps^(1/m)`.
+    /// # Examples
+    /// ```
+    /// use rust_polynomials_lib::coefficients::Complex;
+    /// use rust_polynomials_lib::polynomials::Polynomial;
+    /// let p = Polynomial::from_vector(vec![1.0, 0.0, 1.0]).to_complex(); // x^2 + 1
+    /// let mut roots = p.roots();
+    /// roots.sort_by(|a, b| a.im.total_cmp(&b.im));
+    /// assert!((roots[0] - Complex::new(0.0, -1.0)).norm() < 1e-14);
+    /// assert!((roots[1] - Complex::new(0.0, 1.0)).norm() < 1e-14);
+    /// ```
+    pub fn roots(&self) -> Vec<Complex<f64>> {
+        let degree = self.degree();
+        if degree == 0 {
+            return Vec::new();
+        }
+        let p = self.monic();
+        let derivative = p.derivative();
+
+        // Cauchy's bound: every root lies in the disc of radius 1 + max |a_i|
+        let radius = 1.0
+            + p.coefficients()[..degree]
+                .iter()
+                .map(|c| c.norm())
+                .fold(0.0, f64::max);
+        // the offset avoids starting points symmetric to the real axis
+        let mut roots: Vec<Complex<f64>> = (0..degree)
+            .map(|k| Complex::from_polar(radius, TAU * k as f64 / degree as f64 + 0.4))
+            .collect();
+
+        for _ in 0..MAX_ITERATIONS {
+            let mut converged = true;
+            for k in 0..degree {
+                let value = p.eval(roots[k]);
+                let slope = derivative.eval(roots[k]);
+                if value.is_zero() || slope.is_zero() {
+                    continue;
+                }
+                let ratio = value / slope;
+                let mut repulsion = Complex::zero();
+                for j in 0..degree {
+                    if j != k {
+                        repulsion += (roots[k] - roots[j]).inv();
+                    }
+                }
+                let step = ratio / (Complex::<f64>::one() - ratio * repulsion);
+                if !step.is_finite() {
+                    continue;
+                }
+                roots[k] -= step;
+                if step.norm() > f64::EPSILON * roots[k].norm() {
+                    converged = false;
+                }
+            }
+            if converged {
+                break;
+            }
+        }
+        roots
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_roots(roots: &[Complex<f64>]) -> Polynomial<Complex<f64>> {
+        roots
+            .iter()
+            .fold(Polynomial::from_constant(Complex::one()), |p, &root| {
+                p * Polynomial::from_vector(vec![-root, Complex::one()])
+            })
+    }
+
+    fn sorted(mut roots: Vec<Complex<f64>>) -> Vec<Complex<f64>> {
+        roots.sort_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+        roots
+    }
+
+    #[test]
+    fn test_simple_roots() {
+        let expected = vec![
+            Complex::new(-3.0, 0.0),
+            Complex::new(-1.0, -2.0),
+            Complex::new(-1.0, 2.0),
+            Complex::new(0.0, 0.0),
+            Complex::new(0.5, 0.0),
+            Complex::new(4.0, 1.0),
+        ];
+        let roots = sorted(from_roots(&expected).roots());
+        for (root, expected) in roots.iter().zip(&expected) {
+            assert!((root - expected).norm() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_double_root() {
+        let expected = [Complex::new(2.0, 0.0), Complex::new(2.0, 0.0)];
+        for root in from_roots(&expected).roots() {
+            assert!((root - expected[0]).norm() < 1e-7);
+        }
+    }
+
+    #[test]
+    fn test_scaled_polynomial() {
+        let p = Polynomial::from_vector(vec![-6.0, 0.0, 3.0]).to_complex(); // 3 (x^2 - 2)
+        let roots = sorted(p.roots());
+        assert!((roots[0].re + 2f64.sqrt()).abs() < 1e-14);
+        assert!((roots[1].re - 2f64.sqrt()).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_constant() {
+        assert!(Polynomial::from_constant(Complex::new(2.0, 0.0))
+            .roots()
+            .is_empty());
+    }
+}
@@ -1,6 +1,9 @@
 #![forbid(unsafe_code)]
 
 use num::{One, Zero};
+use rust_polynomials_lib::coefficients::GaussianInteger;
+use rust_polynomials_lib::coefficients::Interval;
+use rust_polynomials_lib::coefficients::Matrix;
 use rust_polynomials_lib::coefficients::NaturalNumber;
 use rust_polynomials_lib::coefficients::RationalNumber;
 use rust_polynomials_lib::coefficients::SquareMatrix;
@@ -204,3 +207,182 @@ fn test_polynomial_of_matrices() {
         ])])
     );
 }
+
+#[test]
+fn test_interpolation_of_rational_numbers() {
+    let points = [
+        (RationalNumber::new(-1, 1), RationalNumber::new(2, 1)),
+        (RationalNumber::new(0, 1), RationalNumber::new(1, 1)),
+        (RationalNumber::new(1, 2), RationalNumber::new(5, 4)),
+    ];
+    let p = Polynomial::interpolate(&points);
+    assert_eq!(
+        p,
+        Polynomial::from_vector(vec![
+            RationalNumber::one(),
+            RationalNumber::zero(),
+            RationalNumber::one()
+        ])
+    );
+    for (x, y) in points {
+        assert_eq!(p.eval(x), y);
+    }
+}
+
+#[test]
+fn test_polynomial_eval_at_interval() {
+    let p = Polynomial::from_vector(vec![0.1, -0.3, 0.2]);
+    let x = Interval::new(-0.5, 0.25);
+    let enclosure = p.eval(x);
+    for it in 0..=100 {
+        let t = -0.5 + 0.75 * it as f64 / 100.0;
+        assert!(enclosure.contains(p.eval(t)));
+    }
+    assert!(enclosure.encloses(&p.range(x)));
+
+    let q = p.to_intervals();
+    assert_eq!(q.eval(x), enclosure);
+}
+
+#[test]
+fn test_gaussian_integer_polynomials_and_matrices() {
+    let i = GaussianInteger::i();
+    // (x - i)(x + i) = x^2 + 1
+    let p = (Polynomial::x() - i) * (Polynomial::x() + i);
+    assert_eq!(
+        p,
+        Polynomial::from_vector(vec![
+            GaussianInteger::one(),
+            GaussianInteger::zero(),
+            GaussianInteger::one()
+        ])
+    );
+    assert!(p.eval(i).is_zero());
+
+    let m = SquareMatrix::new([
+        [GaussianInteger::new(1, 1), GaussianInteger::new(0, 2)],
+        [GaussianInteger::new(3, 0), GaussianInteger::new(1, -1)],
+    ]);
+    assert_eq!(
+        m.clone() * m.clone(),
+        SquareMatrix::new([
+            [GaussianInteger::new(0, 8), GaussianInteger::new(0, 4)],
+            [GaussianInteger::new(6, 0), GaussianInteger::new(0, 4)],
+        ])
+    );
+    let value = p.eval(m.clone());
+    assert_eq!(value, m.clone() * m + SquareMatrix::one());
+}
+
+#[test]
+fn test_determinant_of_matrices_with_custom_coefficients() {
+    let m = SquareMatrix::new([
+        [RationalNumber::new(1, 2), RationalNumber::new(1, 3)],
+        [RationalNumber::new(1, 4), RationalNumber::new(1, 5)],
+    ]);
+    assert_eq!(m.determinant(), RationalNumber::new(1, 60));
+    assert_eq!(m.trace(), RationalNumber::new(7, 10));
+    assert_eq!(
+        m.transpose(),
+        SquareMatrix::new([
+            [RationalNumber::new(1, 2), RationalNumber::new(1, 4)],
+            [RationalNumber::new(1, 3), RationalNumber::new(1, 5)],
+        ])
+    );
+
+    let g = SquareMatrix::new([
+        [
+            GaussianInteger::new(0, 0),
+            GaussianInteger::new(1, 1),
+            GaussianInteger::new(2, 0),
+        ],
+        [
+            GaussianInteger::new(1, 0),
+            GaussianInteger::new(0, 1),
+            GaussianInteger::new(3, -1),
+        ],
+        [
+            GaussianInteger::new(2, 1),
+            GaussianInteger::new(1, 0),
+            GaussianInteger::new(0, 0),
+        ],
+    ]);
+    // expanded along the first row
+    let expected = GaussianInteger::zero()
+        - GaussianInteger::new(1, 1)
+            * (GaussianInteger::zero() - GaussianInteger::new(3, -1) * GaussianInteger::new(2, 1))
+        + GaussianInteger::new(2, 0)
+            * (GaussianInteger::one() - GaussianInteger::new(0, 1) * GaussianInteger::new(2, 1));
+    assert_eq!(g.determinant(), expected);
+}
+
+#[test]
+fn test_cayley_hamilton_with_custom_coefficients() {
+    let m = SquareMatrix::new([
+        [
+            GaussianInteger::new(1, 1),
+            GaussianInteger::new(0, 2),
+            GaussianInteger::new(1, 0),
+        ],
+        [
+            GaussianInteger::new(3, 0),
+            GaussianInteger::new(1, -1),
+            GaussianInteger::new(0, 0),
+        ],
+        [
+            GaussianInteger::new(0, -1),
+            GaussianInteger::new(2, 0),
+            GaussianInteger::new(4, 1),
+        ],
+    ]);
+    let p = m.characteristic_polynomial();
+    assert_eq!(p.degree(), 3);
+    assert_eq!(p.coefficients()[2], -m.trace());
+    assert_eq!(p.coefficients()[0], -m.determinant());
+    assert!(p.eval(m).is_zero());
+}
+
+#[test]
+fn test_dynamic_matrices_with_custom_coefficients() {
+    let square = SquareMatrix::new([
+        [RationalNumber::new(1, 2), RationalNumber::new(1, 3)],
+        [RationalNumber::new(1, 4), RationalNumber::new(1, 5)],
+    ]);
+    let p = Polynomial::from_vector(vec![
+        RationalNumber::new(1, 2),
+        RationalNumber::new(-3, 1),
+        RationalNumber::new(2, 1),
+    ]);
+    let value = p.eval(Matrix::from(square.clone()));
+    assert_eq!(SquareMatrix::try_from(value), Ok(p.eval(square)));
+
+    let column = Matrix::new(
+        2,
+        1,
+        vec![RationalNumber::one(), RationalNumber::new(-1, 1)],
+    );
+    let row = column.transpose();
+    assert_eq!((row * column).shape(), Some((1, 1)));
+}
+
+#[test]
+fn test_least_squares_fit_of_rational_numbers() {
+    let r = |n| RationalNumber::new(n, 1);
+    // y = 1 + 2x - x^2 with the point at x = 2 moved up by one
+    let xs: Vec<RationalNumber> = (0..5).map(r).collect();
+    let ys = vec![r(1), r(2), r(2), r(-2), r(-7)];
+    let perturbation = vec![r(0), r(0), r(1), r(0), r(0)];
+    let exact = Polynomial::from_vector(vec![r(1), r(2), r(-1)]);
+    let weights = vec![r(3), r(1), r(2), r(1), r(5)];
+    for weights in [None, Some(weights.as_slice())] {
+        // the fit is linear in the data and reproduces polynomials of low degree exactly
+        let fit = Polynomial::fit(&xs, &ys, 2, weights);
+        let correction = Polynomial::fit(&xs, &perturbation, 2, weights);
+        assert_eq!(
+            fit.polynomial().clone() - correction.polynomial().clone(),
+            exact
+        );
+        assert_eq!(fit.residuals(), correction.residuals());
+        assert!(!fit.residual_sum_of_squares().is_zero());
+    }
+}